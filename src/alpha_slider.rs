@@ -16,17 +16,29 @@ use floem::{
 use floem_renderer::Renderer;
 
 use crate::checkerboard;
+use crate::config::{ResponseCurve, StepSize};
 use crate::constants;
+use crate::math;
+use crate::theme::Theme;
+use crate::ticks;
 
-/// Rasterize horizontal gradient: opaque `(r, g, b)` on the left -> transparent on the right.
-fn rasterize_alpha_gradient(width: u32, height: u32, r: f64, g: f64, b: f64) -> Vec<u8> {
+/// Rasterize horizontal gradient: opaque `(r, g, b)` on the left -> transparent on the right,
+/// with alpha along the track following `curve`.
+fn rasterize_alpha_gradient(
+    width: u32,
+    height: u32,
+    r: f64,
+    g: f64,
+    b: f64,
+    curve: ResponseCurve,
+) -> Vec<u8> {
     let mut buf = vec![0u8; (width * height * 4) as usize];
     let cr = (r * 255.0 + 0.5) as u8;
     let cg = (g * 255.0 + 0.5) as u8;
     let cb = (b * 255.0 + 0.5) as u8;
     for px in 0..width {
         let t = px as f64 / (width - 1).max(1) as f64; // 0 at left, 1 at right
-        let ca = ((1.0 - t) * 255.0 + 0.5) as u8;
+        let ca = (curve.value_at(1.0 - t) * 255.0 + 0.5) as u8;
         for py in 0..height {
             let offset = ((py * width + px) * 4) as usize;
             buf[offset] = cr;
@@ -46,12 +58,26 @@ enum AlphaUpdate {
 pub(crate) struct AlphaSlider {
     id: ViewId,
     held: bool,
+    /// Whether the control has keyboard focus (draws the focus ring).
+    focused: bool,
     alpha: f64,
     base_r: f64,
     base_g: f64,
     base_b: f64,
     size: floem::taffy::prelude::Size<f32>,
+    theme: Theme,
     on_change: Option<Box<dyn Fn(f64)>>,
+    /// Tick positions (0.0–1.0) drawn over the track, already mapped
+    /// through `curve`.
+    ticks: Vec<f64>,
+    /// Scroll step size.
+    step: StepSize,
+    /// Fill the thumb with the current color over a checkerboard.
+    thumb_preview: bool,
+    /// Restrict alpha to multiples of this increment.
+    snap: Option<f64>,
+    /// Mapping from track position to alpha.
+    curve: ResponseCurve,
     /// Cached gradient image, rasterized at a fixed resolution.
     grad_img: Option<peniko::Image>,
     grad_hash: Vec<u8>,
@@ -62,9 +88,24 @@ pub(crate) struct AlphaSlider {
 ///
 /// - `alpha_signal`: 0.0 (transparent) to 1.0 (opaque).
 /// - `base_color_fn`: returns the current (r, g, b) in 0.0–1.0 for the gradient overlay.
+/// - `thumb_preview`: fill the thumb with the color at its current alpha.
+/// - `ticks`: values (0.0–1.0) to mark on the track.
+/// - `theme`: thumb and track outline colors.
+/// - `step`: scroll step size.
+/// - `quantize`: if set, emitted values snap to `1 / quantize` increments.
+/// - `snap`: if set, dragging and scrolling keep alpha on multiples of it.
+/// - `curve`: mapping from track position to alpha.
+#[allow(clippy::too_many_arguments)]
 pub(crate) fn alpha_slider(
     alpha_signal: RwSignal<f64>,
     base_color_fn: impl Fn() -> (f64, f64, f64) + 'static,
+    thumb_preview: bool,
+    ticks: Vec<f64>,
+    theme: Theme,
+    step: StepSize,
+    quantize: Option<f64>,
+    snap: Option<f64>,
+    curve: ResponseCurve,
 ) -> AlphaSlider {
     let id = ViewId::new();
 
@@ -81,14 +122,24 @@ pub(crate) fn alpha_slider(
     AlphaSlider {
         id,
         held: false,
+        focused: false,
         alpha: 1.0,
         base_r: 0.5,
         base_g: 0.5,
         base_b: 0.5,
         size: Default::default(),
+        theme,
         on_change: Some(Box::new(move |a| {
-            alpha_signal.set(a);
+            alpha_signal.set(match quantize {
+                Some(n) => math::quantize(a, n),
+                None => a,
+            });
         })),
+        thumb_preview,
+        snap,
+        curve,
+        ticks: ticks.into_iter().map(|t| curve.position_of(t)).collect(),
+        step,
         grad_img: None,
         grad_hash: Vec::new(),
         cached_color: (0, 0, 0),
@@ -98,6 +149,7 @@ pub(crate) fn alpha_slider(
             .border_radius(constants::THUMB_RADIUS as f32)
             .cursor(floem::style::CursorStyle::Pointer)
     })
+    .keyboard_navigable()
 }
 
 impl AlphaSlider {
@@ -107,7 +159,13 @@ impl AlphaSlider {
         let usable = w - 2.0 * r;
         if usable > 0.0 {
             // Left = opaque, right = transparent
-            self.alpha = 1.0 - ((x - r) / usable).clamp(0.0, 1.0);
+            let alpha = self
+                .curve
+                .value_at(1.0 - ((x - r) / usable).clamp(0.0, 1.0));
+            self.alpha = match self.snap {
+                Some(step) => math::snap_to_multiple(alpha, step),
+                None => alpha,
+            };
         }
     }
 
@@ -125,7 +183,8 @@ impl AlphaSlider {
 
         let pw = constants::SLIDER_RASTER_WIDTH;
         let ph = constants::SLIDER_RASTER_HEIGHT;
-        let pixels = rasterize_alpha_gradient(pw, ph, self.base_r, self.base_g, self.base_b);
+        let pixels =
+            rasterize_alpha_gradient(pw, ph, self.base_r, self.base_g, self.base_b, self.curve);
         let blob = Blob::new(Arc::new(pixels));
         let img = peniko::Image::new(blob, peniko::Format::Rgba8, pw, ph);
 
@@ -134,6 +193,7 @@ impl AlphaSlider {
             &color_key.0.to_le_bytes(),
             &color_key.1.to_le_bytes(),
             &color_key.2.to_le_bytes(),
+            &self.curve.cache_key(),
         ]
         .concat();
         self.grad_img = Some(img);
@@ -188,10 +248,31 @@ impl View for AlphaSlider {
                 self.held = false;
                 EventPropagation::Continue
             }
+            Event::FocusGained => {
+                self.focused = true;
+                self.id.request_paint();
+                EventPropagation::Continue
+            }
             Event::FocusLost => {
                 self.held = false;
+                self.focused = false;
+                self.id.request_paint();
                 EventPropagation::Continue
             }
+            Event::PointerWheel(e) => {
+                let large = e.modifiers.contains(floem::keyboard::Modifiers::SHIFT);
+                let delta = self.step.for_scroll(e.delta.y, large);
+                self.alpha = match self.snap {
+                    // One increment per notch; smaller steps would round back
+                    Some(step) => math::snap_to_multiple(self.alpha + delta.signum() * step, step),
+                    None => (self.alpha + delta).clamp(0.0, 1.0),
+                };
+                if let Some(cb) = &self.on_change {
+                    cb(self.alpha);
+                }
+                self.id.request_layout();
+                EventPropagation::Stop
+            }
             _ => EventPropagation::Continue,
         }
     }
@@ -229,41 +310,78 @@ impl View for AlphaSlider {
         // Slider outline
         cx.stroke(
             &rrect,
-            Color::rgba8(0, 0, 0, 40),
+            self.theme.track_outline,
             &floem::kurbo::Stroke::new(1.0),
         );
 
+        ticks::paint_ticks(cx, w, h, &self.ticks);
+
         // Filled thumbs (same pattern as color wheel cursor)
         let radius = constants::THUMB_RADIUS;
-        let thumb_x = (radius + (1.0 - self.alpha) * (w - 2.0 * radius)).round();
+        let position = self.curve.position_of(self.alpha);
+        let thumb_x = (radius + (1.0 - position) * (w - 2.0 * radius)).round();
         let thumb_cy = (h / 2.0).round();
         cx.fill(
             &floem::kurbo::Circle::new((thumb_x, thumb_cy), radius + 1.0),
-            Color::rgba8(0, 0, 0, 80),
+            self.theme.thumb_shadow,
             0.0,
         );
         cx.fill(
             &floem::kurbo::Circle::new((thumb_x, thumb_cy), radius),
-            Color::WHITE,
-            0.0,
-        );
-        cx.fill(
-            &floem::kurbo::Circle::new((thumb_x, thumb_cy), radius - 2.0),
-            Color::rgba8(0, 0, 0, 150),
-            0.0,
-        );
-        cx.fill(
-            &floem::kurbo::Circle::new((thumb_x, thumb_cy), radius - 3.0),
-            {
-                let a = self.alpha;
-                let inv = 1.0 - a;
-                Color::rgb(
-                    self.base_r * a + inv,
-                    self.base_g * a + inv,
-                    self.base_b * a + inv,
-                )
-            },
+            self.theme.thumb_ring,
             0.0,
         );
+        if self.thumb_preview {
+            // Actual color at its alpha, composited over a checkerboard
+            let inner_r = radius - 2.0;
+            let inner = floem::kurbo::Circle::new((thumb_x, thumb_cy), inner_r);
+            cx.save();
+            cx.clip(&inner);
+            checkerboard::paint_checkerboard(
+                cx,
+                Rect::new(
+                    thumb_x - inner_r,
+                    thumb_cy - inner_r,
+                    thumb_x + inner_r,
+                    thumb_cy + inner_r,
+                ),
+            );
+            cx.fill(
+                &inner,
+                Color::rgba(self.base_r, self.base_g, self.base_b, self.alpha),
+                0.0,
+            );
+            cx.restore();
+        } else {
+            cx.fill(
+                &floem::kurbo::Circle::new((thumb_x, thumb_cy), radius - 2.0),
+                self.theme.thumb_inner_ring,
+                0.0,
+            );
+            cx.fill(
+                &floem::kurbo::Circle::new((thumb_x, thumb_cy), radius - 3.0),
+                {
+                    let a = self.alpha;
+                    let inv = 1.0 - a;
+                    Color::rgb(
+                        self.base_r * a + inv,
+                        self.base_g * a + inv,
+                        self.base_b * a + inv,
+                    )
+                },
+                0.0,
+            );
+        }
+
+        // Focus ring, inset so it stays within the control's bounds
+        if self.focused {
+            cx.stroke(
+                &rect
+                    .inset(-1.0)
+                    .to_rounded_rect(constants::THUMB_RADIUS - 1.0),
+                self.theme.focus_ring,
+                &floem::kurbo::Stroke::new(2.0),
+            );
+        }
     }
 }
@@ -0,0 +1,160 @@
+//! Eased display of colors set from outside the wheel and sliders.
+//!
+//! The wheel and sliders draw display copies of the HSB and alpha signals.
+//! Their own edits are written straight through to the real signals, while
+//! changes arriving from elsewhere (the bound color, typed values, a
+//! [`PickerHandle`](crate::PickerHandle)) are eased in over a few frames.
+//! Only the display moves; the real signals jump to the new value at once.
+
+use std::cell::Cell;
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+
+use floem::reactive::{RwSignal, SignalGet, SignalUpdate, batch, create_effect};
+
+/// Interval between animation frames.
+const FRAME: Duration = Duration::from_millis(16);
+
+/// Differences at or below this are applied without animating.
+const SETTLE_EPS: f64 = 1e-4;
+
+/// Shared between the effects and the frames of the running animation.
+struct State {
+    /// Bumped to cancel the running animation.
+    generation: Cell<u64>,
+    /// Set while an animation frame writes the display signals.
+    animating: Cell<bool>,
+    /// Set while a user edit is forwarded to the source signals.
+    forwarding: Cell<bool>,
+}
+
+impl State {
+    fn cancel(&self) -> u64 {
+        let next = self.generation.get() + 1;
+        self.generation.set(next);
+        next
+    }
+
+    fn write(&self, display: [RwSignal<f64>; 4], values: [f64; 4]) {
+        self.animating.set(true);
+        batch(|| {
+            for (sig, v) in display.iter().zip(values) {
+                if sig.get_untracked() != v {
+                    sig.set(v);
+                }
+            }
+        });
+        self.animating.set(false);
+    }
+}
+
+/// Display copies of `source` (`[h, s, b, a]`) that ease toward outside
+/// changes over `duration`, moving hue along the shorter arc. Writing a
+/// display signal updates `source` directly and cancels any running
+/// animation.
+pub(crate) fn eased_display(source: [RwSignal<f64>; 4], duration: Duration) -> [RwSignal<f64>; 4] {
+    let display = source.map(|sig| RwSignal::new(sig.get_untracked()));
+    let state = Rc::new(State {
+        generation: Cell::new(0),
+        animating: Cell::new(false),
+        forwarding: Cell::new(false),
+    });
+
+    // Display -> source (wheel and slider edits)
+    let st = state.clone();
+    create_effect(move |_| {
+        let values = display.map(|sig| sig.get());
+        if st.animating.get() {
+            return;
+        }
+        st.cancel();
+        st.forwarding.set(true);
+        batch(|| {
+            for (sig, v) in source.iter().zip(values) {
+                if (sig.get_untracked() - v).abs() > SETTLE_EPS {
+                    sig.set(v);
+                }
+            }
+        });
+        st.forwarding.set(false);
+        // Effects on the source may have adjusted the edit (e.g. hue snapping)
+        st.write(display, source.map(|sig| sig.get_untracked()));
+    });
+
+    // Source -> display (everything else)
+    create_effect(move |_| {
+        let target = source.map(|sig| sig.get());
+        if state.forwarding.get() {
+            return;
+        }
+        let generation = state.cancel();
+        let from = display.map(|sig| sig.get_untracked());
+        let settled = from
+            .iter()
+            .zip(target)
+            .all(|(f, t)| (f - t).abs() <= SETTLE_EPS);
+        if settled || duration.is_zero() {
+            state.write(display, target);
+            return;
+        }
+        schedule_frame(
+            Animation {
+                display,
+                from,
+                target,
+                start: Instant::now(),
+                duration,
+                generation,
+            },
+            state.clone(),
+        );
+    });
+
+    display
+}
+
+/// One running animation from `from` to `target`.
+struct Animation {
+    display: [RwSignal<f64>; 4],
+    from: [f64; 4],
+    target: [f64; 4],
+    start: Instant,
+    duration: Duration,
+    generation: u64,
+}
+
+impl Animation {
+    /// Values at eased progress `t` (0.0–1.0).
+    fn values_at(&self, t: f64) -> [f64; 4] {
+        let [fh, fs, fb, fa] = self.from;
+        let [th, ts, tb, ta] = self.target;
+        let hue_delta = (th - fh + 0.5).rem_euclid(1.0) - 0.5;
+        let lerp = |a: f64, b: f64| a + (b - a) * t;
+        [
+            (fh + hue_delta * t).rem_euclid(1.0),
+            lerp(fs, ts),
+            lerp(fb, tb),
+            lerp(fa, ta),
+        ]
+    }
+}
+
+fn schedule_frame(anim: Animation, state: Rc<State>) {
+    floem::action::exec_after(FRAME, move |_| {
+        if state.generation.get() != anim.generation {
+            return;
+        }
+        let t = (anim.start.elapsed().as_secs_f64() / anim.duration.as_secs_f64()).min(1.0);
+        if t >= 1.0 {
+            state.write(anim.display, anim.target);
+            return;
+        }
+        state.write(anim.display, anim.values_at(ease_out(t)));
+        schedule_frame(anim, state);
+    });
+}
+
+/// Cubic ease-out: fast start, gentle landing.
+fn ease_out(t: f64) -> f64 {
+    1.0 - (1.0 - t).powi(3)
+}
@@ -0,0 +1,406 @@
+//! Combined brightness and alpha pad.
+//!
+//! A 2D area over a checkerboard: brightness runs horizontally (full color
+//! on the left to black on the right, as on the brightness slider) and alpha
+//! vertically (opaque at the top to transparent at the bottom).
+
+use std::sync::Arc;
+
+use floem::kurbo::{Point, Rect};
+use floem::peniko::{self, Blob, Color};
+
+use floem::reactive::{RwSignal, SignalGet, SignalUpdate, batch, create_effect};
+use floem::views::Decorators;
+use floem::{
+    View, ViewId,
+    context::{ComputeLayoutCx, EventCx, PaintCx, UpdateCx},
+    event::{Event, EventPropagation},
+};
+
+use floem_renderer::Renderer;
+
+use crate::checkerboard;
+use crate::config::StepSize;
+use crate::constants;
+use crate::math;
+use crate::theme::Theme;
+
+/// Rasterize the pad: `(r, g, b)` fading to black left to right, and opaque
+/// to transparent top to bottom.
+fn rasterize_pad(width: u32, height: u32, r: f64, g: f64, b: f64) -> Vec<u8> {
+    let mut buf = vec![0u8; (width * height * 4) as usize];
+    for px in 0..width {
+        let tx = px as f64 / (width - 1).max(1) as f64; // 0 at left, 1 at right
+        let cr = ((1.0 - tx) * r * 255.0 + 0.5) as u8;
+        let cg = ((1.0 - tx) * g * 255.0 + 0.5) as u8;
+        let cb = ((1.0 - tx) * b * 255.0 + 0.5) as u8;
+        for py in 0..height {
+            let ty = py as f64 / (height - 1).max(1) as f64; // 0 at top, 1 at bottom
+            let offset = ((py * width + px) * 4) as usize;
+            buf[offset] = cr;
+            buf[offset + 1] = cg;
+            buf[offset + 2] = cb;
+            buf[offset + 3] = ((1.0 - ty) * 255.0 + 0.5) as u8;
+        }
+    }
+    buf
+}
+
+/// Brightness and alpha at `pos` in a pad of `size`, with the cursor kept a
+/// thumb radius inside the edges. Both are clamped to 0.0–1.0.
+fn values_at(pos: Point, size: (f64, f64)) -> (f64, f64) {
+    let r = constants::THUMB_RADIUS;
+    let (w, h) = (size.0 - 2.0 * r, size.1 - 2.0 * r);
+    let along = |v: f64, len: f64| {
+        if len > 0.0 {
+            ((v - r) / len).clamp(0.0, 1.0)
+        } else {
+            0.0
+        }
+    };
+    (1.0 - along(pos.x, w), 1.0 - along(pos.y, h))
+}
+
+/// Cursor center for `brightness` and `alpha`; the inverse of [`values_at`].
+fn cursor_at(brightness: f64, alpha: f64, size: (f64, f64)) -> Point {
+    let r = constants::THUMB_RADIUS;
+    Point::new(
+        r + (1.0 - brightness) * (size.0 - 2.0 * r),
+        r + (1.0 - alpha) * (size.1 - 2.0 * r),
+    )
+}
+
+enum PadUpdate {
+    Values(f64, f64),
+    BaseColor(f64, f64, f64),
+}
+
+pub(crate) struct BrightnessAlphaPad {
+    id: ViewId,
+    held: bool,
+    /// Whether the control has keyboard focus (draws the focus ring).
+    focused: bool,
+    brightness: f64,
+    alpha: f64,
+    base_r: f64,
+    base_g: f64,
+    base_b: f64,
+    size: floem::taffy::prelude::Size<f32>,
+    theme: Theme,
+    on_change: Option<Box<dyn Fn(f64, f64)>>,
+    /// Scroll step size.
+    step: StepSize,
+    /// Restrict alpha to multiples of this increment.
+    alpha_snap: Option<f64>,
+    /// Cached pad image, rasterized at a fixed resolution.
+    grad_img: Option<peniko::Image>,
+    grad_hash: Vec<u8>,
+    cached_color: (u8, u8, u8),
+}
+
+/// Creates a combined brightness (x) and alpha (y) pad.
+///
+/// - `hue`, `saturation`: read-only, used to compute the pad's base color.
+/// - `brightness`: 1.0 (full color, left) to 0.0 (black, right).
+/// - `alpha`: 1.0 (opaque, top) to 0.0 (transparent, bottom).
+/// - `theme`: cursor and outline colors.
+/// - `step`: scroll step size; vertical scrolling changes alpha, horizontal
+///   scrolling brightness.
+/// - `quantize`: if set, emitted brightness and alpha snap to
+///   `1 / quantize` increments of their own.
+/// - `alpha_snap`: if set, dragging keeps alpha on multiples of it.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn brightness_alpha_pad(
+    hue: RwSignal<f64>,
+    saturation: RwSignal<f64>,
+    brightness: RwSignal<f64>,
+    alpha: RwSignal<f64>,
+    theme: Theme,
+    step: StepSize,
+    quantize: (Option<f64>, Option<f64>),
+    alpha_snap: Option<f64>,
+) -> BrightnessAlphaPad {
+    let id = ViewId::new();
+
+    create_effect(move |_| {
+        let b = brightness.get();
+        let a = alpha.get();
+        id.update_state(PadUpdate::Values(b, a));
+    });
+
+    create_effect(move |_| {
+        let h = hue.get();
+        let s = saturation.get();
+        let (r, g, b) = math::hsb_to_rgb(h, s, 1.0);
+        id.update_state(PadUpdate::BaseColor(r, g, b));
+    });
+
+    let (r, g, b) = math::hsb_to_rgb(hue.get_untracked(), saturation.get_untracked(), 1.0);
+    let snap = |val: f64, n: Option<f64>| match n {
+        Some(n) => math::quantize(val, n),
+        None => val,
+    };
+
+    BrightnessAlphaPad {
+        id,
+        held: false,
+        focused: false,
+        brightness: brightness.get_untracked(),
+        alpha: alpha.get_untracked(),
+        base_r: r,
+        base_g: g,
+        base_b: b,
+        size: Default::default(),
+        theme,
+        on_change: Some(Box::new(move |b, a| {
+            batch(|| {
+                brightness.set(snap(b, quantize.0));
+                alpha.set(snap(a, quantize.1));
+            });
+        })),
+        step,
+        alpha_snap,
+        grad_img: None,
+        grad_hash: Vec::new(),
+        cached_color: (0, 0, 0),
+    }
+    .style(|s| {
+        s.height(constants::PAD_HEIGHT)
+            .border_radius(constants::RADIUS)
+            .cursor(floem::style::CursorStyle::Pointer)
+    })
+    .keyboard_navigable()
+}
+
+impl BrightnessAlphaPad {
+    fn update_from_pointer(&mut self, pos: Point) {
+        let size = (self.size.width as f64, self.size.height as f64);
+        let (brightness, alpha) = values_at(pos, size);
+        self.brightness = brightness;
+        self.alpha = match self.alpha_snap {
+            Some(step) => math::snap_to_multiple(alpha, step),
+            None => alpha,
+        };
+    }
+
+    fn emit(&self) {
+        if let Some(cb) = &self.on_change {
+            cb(self.brightness, self.alpha);
+        }
+    }
+
+    /// Rasterize at a fixed resolution, only when the base color changes.
+    /// The renderer scales the image to the actual widget size.
+    fn ensure_pad_image(&mut self) {
+        let color_key = (
+            (self.base_r * 255.0 + 0.5) as u8,
+            (self.base_g * 255.0 + 0.5) as u8,
+            (self.base_b * 255.0 + 0.5) as u8,
+        );
+        if self.grad_img.is_some() && self.cached_color == color_key {
+            return;
+        }
+
+        let pw = constants::SLIDER_RASTER_WIDTH;
+        let ph = constants::PAD_RASTER_HEIGHT;
+        let pixels = rasterize_pad(pw, ph, self.base_r, self.base_g, self.base_b);
+        let blob = Blob::new(Arc::new(pixels));
+        let img = peniko::Image::new(blob, peniko::Format::Rgba8, pw, ph);
+
+        self.grad_hash = [
+            b"pad" as &[u8],
+            &color_key.0.to_le_bytes(),
+            &color_key.1.to_le_bytes(),
+            &color_key.2.to_le_bytes(),
+        ]
+        .concat();
+        self.grad_img = Some(img);
+        self.cached_color = color_key;
+    }
+}
+
+impl View for BrightnessAlphaPad {
+    fn id(&self) -> ViewId {
+        self.id
+    }
+
+    fn update(&mut self, _cx: &mut UpdateCx, state: Box<dyn std::any::Any>) {
+        if let Ok(update) = state.downcast::<PadUpdate>() {
+            match *update {
+                PadUpdate::Values(b, a) => {
+                    self.brightness = b;
+                    self.alpha = a;
+                }
+                PadUpdate::BaseColor(r, g, b) => {
+                    self.base_r = r;
+                    self.base_g = g;
+                    self.base_b = b;
+                }
+            }
+            self.id.request_layout();
+        }
+    }
+
+    fn event_before_children(&mut self, cx: &mut EventCx, event: &Event) -> EventPropagation {
+        match event {
+            Event::PointerDown(e) => {
+                cx.update_active(self.id());
+                self.held = true;
+                self.update_from_pointer(e.pos);
+                self.emit();
+                self.id.request_layout();
+                EventPropagation::Stop
+            }
+            Event::PointerMove(e) => {
+                if self.held {
+                    self.update_from_pointer(e.pos);
+                    self.emit();
+                    self.id.request_layout();
+                    EventPropagation::Stop
+                } else {
+                    EventPropagation::Continue
+                }
+            }
+            Event::PointerUp(_) => {
+                self.held = false;
+                EventPropagation::Continue
+            }
+            Event::FocusGained => {
+                self.focused = true;
+                self.id.request_paint();
+                EventPropagation::Continue
+            }
+            Event::FocusLost => {
+                self.held = false;
+                self.focused = false;
+                self.id.request_paint();
+                EventPropagation::Continue
+            }
+            Event::PointerWheel(e) => {
+                let large = e.modifiers.contains(floem::keyboard::Modifiers::SHIFT);
+                self.alpha = (self.alpha + self.step.for_scroll(e.delta.y, large)).clamp(0.0, 1.0);
+                self.brightness =
+                    (self.brightness + self.step.for_scroll(e.delta.x, large)).clamp(0.0, 1.0);
+                self.emit();
+                self.id.request_layout();
+                EventPropagation::Stop
+            }
+            _ => EventPropagation::Continue,
+        }
+    }
+
+    fn compute_layout(&mut self, _cx: &mut ComputeLayoutCx) -> Option<Rect> {
+        let layout = self.id.get_layout().unwrap_or_default();
+        self.size = layout.size;
+        None
+    }
+
+    fn paint(&mut self, cx: &mut PaintCx) {
+        let w = self.size.width as f64;
+        let h = self.size.height as f64;
+        if w == 0.0 || h == 0.0 {
+            return;
+        }
+        let rect = Rect::new(0.0, 0.0, w, h);
+        let rrect = rect.to_rounded_rect(constants::RADIUS as f64);
+
+        // Checkerboard under the translucent lower part
+        cx.save();
+        cx.clip(&rrect);
+        checkerboard::paint_checkerboard(cx, rect);
+
+        self.ensure_pad_image();
+        if let Some(ref img) = self.grad_img {
+            cx.draw_img(
+                floem_renderer::Img {
+                    img: img.clone(),
+                    hash: &self.grad_hash,
+                },
+                rect,
+            );
+        }
+        cx.restore();
+
+        // Pad outline
+        cx.stroke(
+            &rrect,
+            self.theme.track_outline,
+            &floem::kurbo::Stroke::new(1.0),
+        );
+
+        // Cursor at both values (same pattern as color wheel cursor)
+        let radius = constants::THUMB_RADIUS;
+        let center = cursor_at(self.brightness, self.alpha, (w, h)).round();
+        cx.fill(
+            &floem::kurbo::Circle::new(center, radius + 1.0),
+            self.theme.thumb_shadow,
+            0.0,
+        );
+        cx.fill(
+            &floem::kurbo::Circle::new(center, radius),
+            self.theme.thumb_ring,
+            0.0,
+        );
+        cx.fill(
+            &floem::kurbo::Circle::new(center, radius - 2.0),
+            self.theme.thumb_inner_ring,
+            0.0,
+        );
+        cx.fill(
+            &floem::kurbo::Circle::new(center, radius - 3.0),
+            Color::rgb(
+                self.base_r * self.brightness,
+                self.base_g * self.brightness,
+                self.base_b * self.brightness,
+            ),
+            0.0,
+        );
+
+        // Focus ring, inset so it stays within the control's bounds
+        if self.focused {
+            cx.stroke(
+                &rect
+                    .inset(-1.0)
+                    .to_rounded_rect(constants::RADIUS as f64 - 1.0),
+                self.theme.focus_ring,
+                &floem::kurbo::Stroke::new(2.0),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SIZE: (f64, f64) = (
+        200.0 + 2.0 * constants::THUMB_RADIUS,
+        100.0 + 2.0 * constants::THUMB_RADIUS,
+    );
+
+    fn at(x: f64, y: f64) -> (f64, f64) {
+        let r = constants::THUMB_RADIUS;
+        values_at(Point::new(r + x, r + y), SIZE)
+    }
+
+    #[test]
+    fn pad_maps_x_to_brightness_and_y_to_alpha() {
+        assert_eq!(at(0.0, 0.0), (1.0, 1.0));
+        assert_eq!(at(200.0, 100.0), (0.0, 0.0));
+        assert_eq!(at(50.0, 75.0), (0.75, 0.25));
+    }
+
+    #[test]
+    fn pad_clamps_outside_the_track() {
+        assert_eq!(values_at(Point::new(-20.0, 500.0), SIZE), (1.0, 0.0));
+        assert_eq!(values_at(Point::new(0.0, 0.0), (4.0, 4.0)), (1.0, 1.0));
+    }
+
+    #[test]
+    fn cursor_at_inverts_values_at() {
+        for (b, a) in [(0.0, 1.0), (0.3, 0.8), (1.0, 0.0)] {
+            let (b2, a2) = values_at(cursor_at(b, a, SIZE), SIZE);
+            assert!((b2 - b).abs() < 1e-12 && (a2 - a).abs() < 1e-12);
+        }
+    }
+}
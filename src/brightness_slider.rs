@@ -18,17 +18,29 @@ use floem::{
 
 use floem_renderer::Renderer;
 
+use crate::config::{ResponseCurve, StepSize};
 use crate::constants;
 use crate::math;
+use crate::theme::Theme;
+use crate::ticks;
 
-/// Rasterize horizontal gradient: `(r, g, b)` on the left -> black on the right.
-fn rasterize_brightness_gradient(width: u32, height: u32, r: f64, g: f64, b: f64) -> Vec<u8> {
+/// Rasterize horizontal gradient: `(r, g, b)` on the left -> black on the
+/// right, with brightness along the track following `curve`.
+fn rasterize_brightness_gradient(
+    width: u32,
+    height: u32,
+    r: f64,
+    g: f64,
+    b: f64,
+    curve: ResponseCurve,
+) -> Vec<u8> {
     let mut buf = vec![0u8; (width * height * 4) as usize];
     for px in 0..width {
         let t = px as f64 / (width - 1).max(1) as f64; // 0 at left, 1 at right
-        let cr = ((1.0 - t) * r * 255.0 + 0.5) as u8;
-        let cg = ((1.0 - t) * g * 255.0 + 0.5) as u8;
-        let cb = ((1.0 - t) * b * 255.0 + 0.5) as u8;
+        let v = curve.value_at(1.0 - t);
+        let cr = (v * r * 255.0 + 0.5) as u8;
+        let cg = (v * g * 255.0 + 0.5) as u8;
+        let cb = (v * b * 255.0 + 0.5) as u8;
         for py in 0..height {
             let offset = ((py * width + px) * 4) as usize;
             buf[offset] = cr;
@@ -48,12 +60,22 @@ enum BrightnessUpdate {
 pub(crate) struct BrightnessSlider {
     id: ViewId,
     held: bool,
+    /// Whether the control has keyboard focus (draws the focus ring).
+    focused: bool,
     brightness: f64,
     base_r: f64,
     base_g: f64,
     base_b: f64,
     size: floem::taffy::prelude::Size<f32>,
+    theme: Theme,
     on_change: Option<Box<dyn Fn(f64)>>,
+    /// Tick positions (0.0–1.0) drawn over the track, already mapped
+    /// through `curve`.
+    ticks: Vec<f64>,
+    /// Scroll step size.
+    step: StepSize,
+    /// Mapping from track position to brightness.
+    curve: ResponseCurve,
     /// Cached gradient image, rasterized at a fixed resolution.
     grad_img: Option<peniko::Image>,
     grad_hash: Vec<u8>,
@@ -64,10 +86,21 @@ pub(crate) struct BrightnessSlider {
 ///
 /// - `hue`, `saturation`: read-only, used to compute the gradient's end color.
 /// - `brightness`: 0.0 (black, left) to 1.0 (full color, right).
+/// - `ticks`: values (0.0–1.0) to mark on the track.
+/// - `theme`: thumb and track outline colors.
+/// - `step`: scroll step size.
+/// - `quantize`: if set, emitted values snap to `1 / quantize` increments.
+/// - `curve`: mapping from track position to brightness.
+#[allow(clippy::too_many_arguments)]
 pub(crate) fn brightness_slider(
     hue: RwSignal<f64>,
     saturation: RwSignal<f64>,
     brightness: RwSignal<f64>,
+    ticks: Vec<f64>,
+    theme: Theme,
+    step: StepSize,
+    quantize: Option<f64>,
+    curve: ResponseCurve,
 ) -> BrightnessSlider {
     let id = ViewId::new();
 
@@ -88,14 +121,22 @@ pub(crate) fn brightness_slider(
     BrightnessSlider {
         id,
         held: false,
+        focused: false,
         brightness: brightness.get_untracked(),
         base_r: r,
         base_g: g,
         base_b: b,
         size: Default::default(),
+        theme,
         on_change: Some(Box::new(move |val| {
-            brightness.set(val);
+            brightness.set(match quantize {
+                Some(n) => math::quantize(val, n),
+                None => val,
+            });
         })),
+        ticks: ticks.into_iter().map(|t| curve.position_of(t)).collect(),
+        step,
+        curve,
         grad_img: None,
         grad_hash: Vec::new(),
         cached_color: (0, 0, 0),
@@ -105,6 +146,7 @@ pub(crate) fn brightness_slider(
             .border_radius(constants::THUMB_RADIUS as f32)
             .cursor(floem::style::CursorStyle::Pointer)
     })
+    .keyboard_navigable()
 }
 
 impl BrightnessSlider {
@@ -114,7 +156,9 @@ impl BrightnessSlider {
         let usable = w - 2.0 * r;
         if usable > 0.0 {
             // Left = full brightness, right = black
-            self.brightness = 1.0 - ((x - r) / usable).clamp(0.0, 1.0);
+            self.brightness = self
+                .curve
+                .value_at(1.0 - ((x - r) / usable).clamp(0.0, 1.0));
         }
     }
 
@@ -132,7 +176,14 @@ impl BrightnessSlider {
 
         let pw = constants::SLIDER_RASTER_WIDTH;
         let ph = constants::SLIDER_RASTER_HEIGHT;
-        let pixels = rasterize_brightness_gradient(pw, ph, self.base_r, self.base_g, self.base_b);
+        let pixels = rasterize_brightness_gradient(
+            pw,
+            ph,
+            self.base_r,
+            self.base_g,
+            self.base_b,
+            self.curve,
+        );
         let blob = Blob::new(Arc::new(pixels));
         let img = peniko::Image::new(blob, peniko::Format::Rgba8, pw, ph);
 
@@ -141,6 +192,7 @@ impl BrightnessSlider {
             &color_key.0.to_le_bytes(),
             &color_key.1.to_le_bytes(),
             &color_key.2.to_le_bytes(),
+            &self.curve.cache_key(),
         ]
         .concat();
         self.grad_img = Some(img);
@@ -195,10 +247,27 @@ impl View for BrightnessSlider {
                 self.held = false;
                 EventPropagation::Continue
             }
+            Event::FocusGained => {
+                self.focused = true;
+                self.id.request_paint();
+                EventPropagation::Continue
+            }
             Event::FocusLost => {
                 self.held = false;
+                self.focused = false;
+                self.id.request_paint();
                 EventPropagation::Continue
             }
+            Event::PointerWheel(e) => {
+                let large = e.modifiers.contains(floem::keyboard::Modifiers::SHIFT);
+                let delta = self.step.for_scroll(e.delta.y, large);
+                self.brightness = (self.brightness + delta).clamp(0.0, 1.0);
+                if let Some(cb) = &self.on_change {
+                    cb(self.brightness);
+                }
+                self.id.request_layout();
+                EventPropagation::Stop
+            }
             _ => EventPropagation::Continue,
         }
     }
@@ -239,27 +308,30 @@ impl View for BrightnessSlider {
         // Slider outline
         cx.stroke(
             &rrect,
-            Color::rgba8(0, 0, 0, 40),
+            self.theme.track_outline,
             &floem::kurbo::Stroke::new(1.0),
         );
 
+        ticks::paint_ticks(cx, w, h, &self.ticks);
+
         // Filled thumbs (same pattern as color wheel cursor)
         let radius = constants::THUMB_RADIUS;
-        let thumb_x = (radius + (1.0 - self.brightness) * (w - 2.0 * radius)).round();
+        let position = self.curve.position_of(self.brightness);
+        let thumb_x = (radius + (1.0 - position) * (w - 2.0 * radius)).round();
         let thumb_cy = (h / 2.0).round();
         cx.fill(
             &floem::kurbo::Circle::new((thumb_x, thumb_cy), radius + 1.0),
-            Color::rgba8(0, 0, 0, 80),
+            self.theme.thumb_shadow,
             0.0,
         );
         cx.fill(
             &floem::kurbo::Circle::new((thumb_x, thumb_cy), radius),
-            Color::WHITE,
+            self.theme.thumb_ring,
             0.0,
         );
         cx.fill(
             &floem::kurbo::Circle::new((thumb_x, thumb_cy), radius - 2.0),
-            Color::rgba8(0, 0, 0, 150),
+            self.theme.thumb_inner_ring,
             0.0,
         );
         cx.fill(
@@ -271,5 +343,16 @@ impl View for BrightnessSlider {
             ),
             0.0,
         );
+
+        // Focus ring, inset so it stays within the control's bounds
+        if self.focused {
+            cx.stroke(
+                &rect
+                    .inset(-1.0)
+                    .to_rounded_rect(constants::THUMB_RADIUS - 1.0),
+                self.theme.focus_ring,
+                &floem::kurbo::Stroke::new(2.0),
+            );
+        }
     }
 }
@@ -0,0 +1,108 @@
+//! Stacked bar showing the color's red, green and blue shares.
+//!
+//! Each segment's length is its channel's fraction of `r + g + b`, so the
+//! bar reads as the color's makeup rather than its brightness. Black has no
+//! makeup and shows an empty track.
+
+use floem::kurbo::{Rect, Stroke};
+use floem::peniko::Color;
+use floem::reactive::{RwSignal, SignalGet, create_effect};
+use floem::views::Decorators;
+use floem::{
+    View, ViewId,
+    context::{ComputeLayoutCx, PaintCx, UpdateCx},
+};
+
+use floem_renderer::Renderer;
+
+use crate::color::SolidColor;
+use crate::constants;
+
+const SEGMENTS: [Color; 3] = [
+    Color::rgb8(230, 60, 60),
+    Color::rgb8(60, 180, 75),
+    Color::rgb8(60, 110, 230),
+];
+const TRACK: Color = Color::rgb8(225, 225, 225);
+const BORDER: Color = Color::rgb8(180, 180, 180);
+
+/// Height of the bar.
+const BAR_HEIGHT: f32 = 6.0;
+
+/// Fractions of `r + g + b` held by each channel, or `None` for black.
+fn shares(r: f64, g: f64, b: f64) -> Option<[f64; 3]> {
+    let total = r + g + b;
+    (total > 0.0).then(|| [r / total, g / total, b / total])
+}
+
+pub(crate) struct ChannelBar {
+    id: ViewId,
+    size: floem::taffy::prelude::Size<f32>,
+    shares: Option<[f64; 3]>,
+}
+
+/// A thin bar split into red, green and blue segments sized by each
+/// channel's share of `color`.
+pub(crate) fn channel_bar(color: RwSignal<SolidColor>) -> ChannelBar {
+    let id = ViewId::new();
+    create_effect(move |_| {
+        let c = color.get();
+        id.update_state(shares(c.r(), c.g(), c.b()));
+    });
+    let c = color.get_untracked();
+    ChannelBar {
+        id,
+        size: Default::default(),
+        shares: shares(c.r(), c.g(), c.b()),
+    }
+    .style(|s| s.height(BAR_HEIGHT))
+}
+
+impl View for ChannelBar {
+    fn id(&self) -> ViewId {
+        self.id
+    }
+
+    fn update(&mut self, _cx: &mut UpdateCx, state: Box<dyn std::any::Any>) {
+        if let Ok(shares) = state.downcast::<Option<[f64; 3]>>() {
+            self.shares = *shares;
+            self.id.request_paint();
+        }
+    }
+
+    fn compute_layout(&mut self, _cx: &mut ComputeLayoutCx) -> Option<Rect> {
+        let layout = self.id.get_layout().unwrap_or_default();
+        self.size = layout.size;
+        None
+    }
+
+    fn paint(&mut self, cx: &mut PaintCx) {
+        let w = self.size.width as f64;
+        let h = self.size.height as f64;
+        if w == 0.0 || h == 0.0 {
+            return;
+        }
+        let rect = Rect::new(0.0, 0.0, w, h);
+        let radius = (constants::RADIUS as f64).min(h / 2.0);
+        let rounded = rect.to_rounded_rect(radius);
+        cx.save();
+        cx.clip(&rounded);
+        cx.fill(&rect, TRACK, 0.0);
+        if let Some(shares) = self.shares {
+            let mut x = 0.0;
+            for (share, color) in shares.into_iter().zip(SEGMENTS) {
+                let next = x + share * w;
+                if next > x {
+                    cx.fill(&Rect::new(x, 0.0, next, h), color, 0.0);
+                }
+                x = next;
+            }
+        }
+        cx.restore();
+        cx.stroke(
+            &Rect::new(0.5, 0.5, w - 0.5, h - 0.5).to_rounded_rect(radius),
+            BORDER,
+            &Stroke::new(1.0),
+        );
+    }
+}
@@ -8,8 +8,16 @@ use floem_renderer::Renderer;
 
 use crate::constants;
 
-const LIGHT: Color = Color::rgb8(255, 255, 255);
-const DARK: Color = Color::rgb8(204, 204, 204);
+const LIGHT: Color = Color::rgb8(
+    constants::CHECKER_LIGHT,
+    constants::CHECKER_LIGHT,
+    constants::CHECKER_LIGHT,
+);
+const DARK: Color = Color::rgb8(
+    constants::CHECKER_DARK,
+    constants::CHECKER_DARK,
+    constants::CHECKER_DARK,
+);
 
 /// Paint a checkerboard pattern into `rect`.
 pub(crate) fn paint_checkerboard(cx: &mut PaintCx, rect: Rect) {
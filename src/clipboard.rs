@@ -0,0 +1,79 @@
+//! Clipboard used by the copy buttons.
+//!
+//! Defaults to the system clipboard through `arboard`. Applications that
+//! already own a clipboard handle (a game engine, a Wayland client with its
+//! own data-device handling) can route copies through it instead via
+//! [`PickerConfig::clipboard`](crate::PickerConfig::clipboard).
+
+use std::fmt;
+use std::rc::Rc;
+
+/// A destination for copied text.
+pub trait Clipboard {
+    /// Place `text` on the clipboard. Failures are ignored; a copy button
+    /// has nowhere to report them.
+    fn set_text(&self, text: &str);
+}
+
+/// The system clipboard, via `arboard`. Opens a new handle per copy.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClipboard;
+
+impl Clipboard for SystemClipboard {
+    fn set_text(&self, text: &str) {
+        if let Ok(mut clipboard) = arboard::Clipboard::new() {
+            let _ = clipboard.set_text(text);
+        }
+    }
+}
+
+/// Shared handle to a [`Clipboard`], as stored in
+/// [`PickerConfig`](crate::PickerConfig).
+#[derive(Clone)]
+pub struct SharedClipboard(pub Rc<dyn Clipboard>);
+
+impl SharedClipboard {
+    pub fn new(clipboard: impl Clipboard + 'static) -> Self {
+        Self(Rc::new(clipboard))
+    }
+}
+
+impl Default for SharedClipboard {
+    fn default() -> Self {
+        Self::new(SystemClipboard)
+    }
+}
+
+impl fmt::Debug for SharedClipboard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SharedClipboard(..)")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::cell::RefCell;
+
+    /// Records copied text instead of touching the system clipboard.
+    #[derive(Default)]
+    struct Recorder(Rc<RefCell<Vec<String>>>);
+
+    impl Clipboard for Recorder {
+        fn set_text(&self, text: &str) {
+            self.0.borrow_mut().push(text.to_string());
+        }
+    }
+
+    #[test]
+    fn copies_reach_an_injected_clipboard() {
+        let copied = Rc::new(RefCell::new(Vec::new()));
+        let shared = SharedClipboard::new(Recorder(copied.clone()));
+        // Each copy button holds its own clone of the config's handle
+        let (hex_button, rgb_button) = (shared.clone(), shared);
+        hex_button.0.set_text("#3B82F6");
+        rgb_button.0.set_text("rgb(59, 130, 246)");
+        assert_eq!(*copied.borrow(), ["#3B82F6", "rgb(59, 130, 246)"]);
+    }
+}
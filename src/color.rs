@@ -6,7 +6,70 @@
 use std::fmt;
 use std::str::FromStr;
 
+use crate::constants;
 use crate::math;
+use crate::named;
+
+/// Names of the twelve 30° hue buckets used by [`SolidColor::hue_name`],
+/// starting at red (0°) and going round the wheel.
+const HUE_NAMES: [&str; 12] = [
+    "red",
+    "orange",
+    "yellow",
+    "chartreuse",
+    "green",
+    "spring green",
+    "cyan",
+    "azure",
+    "blue",
+    "violet",
+    "magenta",
+    "rose",
+];
+
+/// HSB saturation or brightness below which [`SolidColor::hue_name`]
+/// reports gray.
+const HUE_NAME_GRAY_EPS: f64 = 0.1;
+
+/// Why a color string failed to parse.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorParseError {
+    /// Hex string is not 3, 4, 6, or 8 digits long.
+    BadLength,
+    /// Hex string contains a character outside `0-9`, `a-f`, `A-F`.
+    NonHexDigit,
+    /// Input doesn't look like any supported notation.
+    UnknownFormat,
+    /// Input looks like a color name but isn't a known one.
+    UnknownName,
+}
+
+impl fmt::Display for ColorParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let msg = match self {
+            Self::BadLength => "hex color must be 3, 4, 6, or 8 digits",
+            Self::NonHexDigit => "hex color contains a non-hex digit",
+            Self::UnknownFormat => "unrecognized color format",
+            Self::UnknownName => "unknown color name",
+        };
+        f.write_str(msg)
+    }
+}
+
+impl std::error::Error for ColorParseError {}
+
+/// Color space in which compositing is computed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BlendSpace {
+    /// Blend the gamma-encoded sRGB channels, as most renderers do.
+    #[default]
+    Srgb,
+    /// Blend in linear light, which is physically accurate.
+    Linear,
+}
+
+/// Per-channel transfer function, e.g. sRGB decoding to linear light.
+type Transfer = fn(f64) -> f64;
 
 /// RGBA color with components in the 0.0–1.0 range.
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -72,53 +135,182 @@ impl SolidColor {
         )
     }
 
-    /// Parse a hex string (with or without `#`, 3, 6, or 8 chars).
+    /// Pack as `0xRRGGBBAA`: red in the high byte, alpha in the low byte.
+    pub fn to_rgba_u32(&self) -> u32 {
+        let (r, g, b) = self.to_rgb();
+        let a = (self.a * 255.0).round() as u8;
+        u32::from_be_bytes([r, g, b, a])
+    }
+
+    /// Unpack `0xRRGGBBAA` (the layout of [`to_rgba_u32`](Self::to_rgba_u32)).
+    pub fn from_rgba_u32(packed: u32) -> Self {
+        let [r, g, b, a] = packed.to_be_bytes().map(|c| c as f64 / 255.0);
+        Self::from_rgba(r, g, b, a)
+    }
+
+    /// Pack as `0xAARRGGBB`: alpha in the high byte, blue in the low byte.
+    pub fn to_argb_u32(&self) -> u32 {
+        self.to_rgba_u32().rotate_right(8)
+    }
+
+    /// Unpack `0xAARRGGBB` (the layout of [`to_argb_u32`](Self::to_argb_u32)).
+    pub fn from_argb_u32(packed: u32) -> Self {
+        Self::from_rgba_u32(packed.rotate_left(8))
+    }
+
+    /// Channels as `[r, g, b, a]` in 0.0–1.0, e.g. for a shader uniform.
+    pub fn to_array(&self) -> [f32; 4] {
+        self.to_array_f64().map(|c| c as f32)
+    }
+
+    /// Create from `[r, g, b, a]` (the layout of [`to_array`](Self::to_array)).
+    /// Values are clamped to 0.0–1.0.
+    pub fn from_array(rgba: [f32; 4]) -> Self {
+        Self::from_array_f64(rgba.map(f64::from))
+    }
+
+    /// Channels as `[r, g, b, a]` in 0.0–1.0, at full precision.
+    pub fn to_array_f64(&self) -> [f64; 4] {
+        [self.r, self.g, self.b, self.a]
+    }
+
+    /// Create from `[r, g, b, a]` (the layout of
+    /// [`to_array_f64`](Self::to_array_f64)). Values are clamped to 0.0–1.0.
+    pub fn from_array_f64([r, g, b, a]: [f64; 4]) -> Self {
+        Self::from_rgba(r, g, b, a)
+    }
+
+    /// Parse a hex string (with or without `#`, 3, 4, 6, or 8 chars).
+    ///
+    /// 8-char hex is interpreted as RRGGBBAA and 4-char hex as RGBA. 3 and
+    /// 6-char hex default to full opacity.
     ///
-    /// 8-char hex is interpreted as RRGGBBAA. 3 and 6-char hex default to full opacity.
+    /// Channels are stored as exact multiples of 1/255, so
+    /// [`to_hex`](Self::to_hex) returns the same digits (uppercased, with
+    /// 3-char hex expanded) until the color is edited.
     pub fn from_hex(hex: &str) -> Option<Self> {
+        Self::try_from_hex(hex).ok()
+    }
+
+    /// Like [`from_hex`](Self::from_hex), but reports why parsing failed.
+    pub fn try_from_hex(hex: &str) -> Result<Self, ColorParseError> {
         let stripped = hex.trim_start_matches('#');
         if !stripped.chars().all(|c| c.is_ascii_hexdigit()) {
-            return None;
-        }
-        match stripped.len() {
-            3 => {
-                let r = u8::from_str_radix(&stripped[0..1], 16).ok()?;
-                let g = u8::from_str_radix(&stripped[1..2], 16).ok()?;
-                let b = u8::from_str_radix(&stripped[2..3], 16).ok()?;
-                Some(Self {
-                    r: (r * 17) as f64 / 255.0,
-                    g: (g * 17) as f64 / 255.0,
-                    b: (b * 17) as f64 / 255.0,
-                    a: 1.0,
-                })
-            }
-            6 => {
-                let r = u8::from_str_radix(&stripped[0..2], 16).ok()?;
-                let g = u8::from_str_radix(&stripped[2..4], 16).ok()?;
-                let b = u8::from_str_radix(&stripped[4..6], 16).ok()?;
-                Some(Self {
-                    r: r as f64 / 255.0,
-                    g: g as f64 / 255.0,
-                    b: b as f64 / 255.0,
-                    a: 1.0,
-                })
+            return Err(ColorParseError::NonHexDigit);
+        }
+        let byte = |i: usize| u8::from_str_radix(&stripped[i..i + 2], 16);
+        let nibble = |i: usize| u8::from_str_radix(&stripped[i..i + 1], 16).map(|n| n * 17);
+        let (r, g, b, a) = match stripped.len() {
+            3 => (nibble(0), nibble(1), nibble(2), Ok(255)),
+            4 => (nibble(0), nibble(1), nibble(2), nibble(3)),
+            6 => (byte(0), byte(2), byte(4), Ok(255)),
+            8 => (byte(0), byte(2), byte(4), byte(6)),
+            _ => return Err(ColorParseError::BadLength),
+        };
+        match (r, g, b, a) {
+            (Ok(r), Ok(g), Ok(b), Ok(a)) => Ok(Self {
+                r: r as f64 / 255.0,
+                g: g as f64 / 255.0,
+                b: b as f64 / 255.0,
+                a: a as f64 / 255.0,
+            }),
+            _ => Err(ColorParseError::NonHexDigit),
+        }
+    }
+
+    /// Parse a CSS color function as copied from browser devtools, falling
+    /// back to [`from_hex`](Self::from_hex) for a bare hex token.
+    ///
+    /// Recognizes `rgb()`/`rgba()`, `hsl()`/`hsla()` and `hwb()`, in the
+    /// legacy comma syntax (`rgba(59, 130, 246, 0.5)`) and the space syntax
+    /// with slash alpha (`hsl(210 100% 60% / 50%)`).
+    ///
+    /// - rgb channels are numbers (0–255) or percents.
+    /// - Hues are degrees, bare or with a `deg`, `turn`, `grad` or `rad` unit.
+    /// - Saturation, lightness, whiteness and blackness must be percents.
+    /// - Alpha is a number (0–1) or a percent.
+    ///
+    /// Out-of-range values are clamped; malformed input returns `None`.
+    pub fn from_css(s: &str) -> Option<Self> {
+        let s = s.trim();
+        let lower = s.to_ascii_lowercase();
+        let Some((name, args)) = lower.split_once('(') else {
+            return Self::from_hex(s);
+        };
+        let (parts, alpha) = css_args(args.strip_suffix(')')?)?;
+        let a = match alpha {
+            Some(v) => css_number(v, 1.0)?,
+            None => 1.0,
+        };
+        match name.trim_end() {
+            "rgb" | "rgba" => {
+                let channel = |v: &str| css_number(v, 255.0);
+                Some(Self::from_rgba(
+                    channel(parts[0])?,
+                    channel(parts[1])?,
+                    channel(parts[2])?,
+                    a,
+                ))
             }
-            8 => {
-                let r = u8::from_str_radix(&stripped[0..2], 16).ok()?;
-                let g = u8::from_str_radix(&stripped[2..4], 16).ok()?;
-                let b = u8::from_str_radix(&stripped[4..6], 16).ok()?;
-                let a = u8::from_str_radix(&stripped[6..8], 16).ok()?;
-                Some(Self {
-                    r: r as f64 / 255.0,
-                    g: g as f64 / 255.0,
-                    b: b as f64 / 255.0,
-                    a: a as f64 / 255.0,
-                })
+            "hsl" | "hsla" => Some(Self::from_hsl(
+                css_hue(parts[0])?,
+                css_percent(parts[1])?,
+                css_percent(parts[2])?,
+                a,
+            )),
+            "hwb" => {
+                let (w, b) = (css_percent(parts[1])?, css_percent(parts[2])?);
+                let hue = css_hue(parts[0])?;
+                // Whiteness + blackness beyond 100% is a gray of their ratio
+                if w + b >= 1.0 {
+                    let gray = w / (w + b);
+                    return Some(Self::from_rgba(gray, gray, gray, a));
+                }
+                let v = 1.0 - b;
+                Some(Self::from_hsb(hue, 1.0 - w / v, v, a))
             }
             _ => None,
         }
     }
 
+    /// Look up a CSS named color (e.g. `"cornflowerblue"`), case-insensitive.
+    pub fn from_name(name: &str) -> Option<Self> {
+        named::lookup(name).map(|(r, g, b)| Self::from_rgb(r, g, b))
+    }
+
+    /// Parse any supported color notation: hex (with or without `#`), a CSS
+    /// color name, or a CSS color function (see
+    /// [`from_css`](Self::from_css)).
+    pub fn parse(s: &str) -> Option<Self> {
+        Self::try_parse(s).ok()
+    }
+
+    /// Like [`parse`](Self::parse), but reports why parsing failed.
+    ///
+    /// Names are tried before bare hex, so inputs made only of letters
+    /// report [`ColorParseError::UnknownName`] rather than a hex error.
+    pub fn try_parse(s: &str) -> Result<Self, ColorParseError> {
+        let s = s.trim();
+        if s.starts_with('#') {
+            return Self::try_from_hex(s);
+        }
+        if s.ends_with(')') {
+            return Self::from_css(s).ok_or(ColorParseError::UnknownFormat);
+        }
+        if !s.is_empty() && s.chars().all(|c| c.is_ascii_alphabetic()) {
+            if let Some(c) = Self::from_name(s) {
+                return Ok(c);
+            }
+            if !s.chars().all(|c| c.is_ascii_hexdigit()) {
+                return Err(ColorParseError::UnknownName);
+            }
+        }
+        if !s.is_empty() && s.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Self::try_from_hex(s);
+        }
+        Err(ColorParseError::UnknownFormat)
+    }
+
     /// Format as uppercase hex (no `#` prefix).
     ///
     /// Returns 6 chars (RRGGBB) when alpha is 1.0.
@@ -133,12 +325,48 @@ impl SolidColor {
         }
     }
 
+    /// Convert to an 8-digit hex string (uppercase, no `#`), always
+    /// including alpha.
+    pub fn to_hex8(&self) -> String {
+        let (r, g, b) = self.to_rgb();
+        let a = (self.a * 255.0).round() as u8;
+        format!("{:02X}{:02X}{:02X}{:02X}", r, g, b, a)
+    }
+
+    /// URL fragment for deep links, e.g. `#color=3B82F6FF`.
+    pub fn to_url_fragment(&self) -> String {
+        format!("#color={}", self.to_hex8())
+    }
+
+    /// Parse a fragment made by [`to_url_fragment`](Self::to_url_fragment).
+    ///
+    /// The leading `#` is optional and `color=` may be one of several
+    /// `&`-separated parameters. Returns `None` if there is no `color`
+    /// parameter or its value isn't a 3, 4, 6, or 8 digit hex color.
+    pub fn from_url_fragment(fragment: &str) -> Option<Self> {
+        fragment
+            .strip_prefix('#')
+            .unwrap_or(fragment)
+            .split('&')
+            .find_map(|param| param.strip_prefix("color="))
+            .filter(|hex| !hex.starts_with('#'))
+            .and_then(Self::from_hex)
+    }
+
     /// Create from HSB/HSV values (all 0.0–1.0).
     pub fn from_hsb(h: f64, s: f64, b: f64, a: f64) -> Self {
         let (r, g, bl) = math::hsb_to_rgb(h, s, b);
         Self { r, g, b: bl, a }
     }
 
+    /// Create from HSB in the picker's display units: hue in degrees
+    /// (0–360), saturation, brightness and alpha in percent (0–100).
+    /// Out-of-range values are clamped.
+    pub fn from_hsb_degrees(h: u16, s: u8, b: u8, a: u8) -> Self {
+        let (h, s, b, a) = display_to_unit(h, s, b, a);
+        Self::from_hsb(h, s, b, a)
+    }
+
     /// Convert to HSB (all 0.0–1.0). Returns (h, s, b).
     pub fn to_hsb(&self) -> (f64, f64, f64) {
         math::rgb_to_hsb(self.r, self.g, self.b)
@@ -151,12 +379,245 @@ impl SolidColor {
         Self { r, g, b: bl, a }
     }
 
+    /// Create from HSL in the picker's display units: hue in degrees
+    /// (0–360), saturation, lightness and alpha in percent (0–100).
+    /// Out-of-range values are clamped.
+    pub fn from_hsl_degrees(h: u16, s: u8, l: u8, a: u8) -> Self {
+        let (h, s, l, a) = display_to_unit(h, s, l, a);
+        Self::from_hsl(h, s, l, a)
+    }
+
     /// Convert to HSL (all 0.0–1.0). Returns (h, s, l).
     pub fn to_hsl(&self) -> (f64, f64, f64) {
         let (h, s, v) = math::rgb_to_hsb(self.r, self.g, self.b);
         math::hsb_to_hsl(h, s, v)
     }
 
+    /// Create from naive device CMYK (all 0.0–1.0, no color profile).
+    /// Values are clamped.
+    pub fn from_cmyk(c: f64, m: f64, y: f64, k: f64, a: f64) -> Self {
+        let [c, m, y, k] = [c, m, y, k].map(|v| v.clamp(0.0, 1.0));
+        let (r, g, b) = math::cmyk_to_rgb(c, m, y, k);
+        Self::from_rgba(r, g, b, a)
+    }
+
+    /// Convert to naive device CMYK (all 0.0–1.0). Returns (c, m, y, k),
+    /// with black as pure K. Alpha is ignored.
+    pub fn to_cmyk(&self) -> (f64, f64, f64, f64) {
+        math::rgb_to_cmyk(self.r, self.g, self.b)
+    }
+
+    /// Whether the color is achromatic to within `eps`: HSB saturation or
+    /// brightness below `eps`. Such colors carry no meaningful hue.
+    pub fn is_approximately_gray(&self, eps: f64) -> bool {
+        let (_, s, b) = self.to_hsb();
+        s < eps || b < eps
+    }
+
+    /// Coarse hue category, for grouping or sorting colors by name.
+    ///
+    /// The wheel is split into twelve 30° buckets centered on red (0°),
+    /// orange (30°), yellow, chartreuse, green, spring green, cyan, azure,
+    /// blue, violet, magenta and rose (330°); each bucket runs from 15°
+    /// below its center up to, but not including, 15° above. Colors with
+    /// HSB saturation or brightness under 10% are `"gray"`. Alpha is ignored.
+    pub fn hue_name(&self) -> &'static str {
+        if self.is_approximately_gray(HUE_NAME_GRAY_EPS) {
+            return "gray";
+        }
+        let (h, _, _) = self.to_hsb();
+        let bucket = ((h * 12.0 + 0.5).floor() as usize) % 12;
+        HUE_NAMES[bucket]
+    }
+
+    /// Warm/cool bias from -1.0 (cool) to 1.0 (warm).
+    ///
+    /// Follows a cosine of hue peaking at orange (30°, +1.0) and bottoming
+    /// out at azure blue (210°, -1.0): reds, oranges and yellows are warm,
+    /// cyans and blues are cool, and greens (120°) and magentas (300°) are
+    /// neutral. Scaled by HSB saturation, so grays are 0.0.
+    pub fn temperature_bias(&self) -> f64 {
+        let (h, s, _) = self.to_hsb();
+        s * ((h - 30.0 / 360.0) * std::f64::consts::TAU).cos()
+    }
+
+    /// Raise HSL lightness by `amount` (0.0–1.0), clamped at white.
+    /// Hue, saturation and alpha are kept.
+    pub fn lighten(&self, amount: f64) -> SolidColor {
+        let (h, s, l) = self.to_hsl();
+        Self::from_hsl(h, s, (l + amount).clamp(0.0, 1.0), self.a)
+    }
+
+    /// Lower HSL lightness by `amount` (0.0–1.0), clamped at black.
+    /// Hue, saturation and alpha are kept.
+    pub fn darken(&self, amount: f64) -> SolidColor {
+        self.lighten(-amount)
+    }
+
+    /// Raise HSL saturation by `amount` (0.0–1.0), clamped at full
+    /// saturation. Hue, lightness and alpha are kept.
+    pub fn saturate(&self, amount: f64) -> SolidColor {
+        let (h, s, l) = self.to_hsl();
+        Self::from_hsl(h, (s + amount).clamp(0.0, 1.0), l, self.a)
+    }
+
+    /// Lower HSL saturation by `amount` (0.0–1.0); 1.0 gives the gray of
+    /// the same HSL lightness. Hue, lightness and alpha are kept.
+    pub fn desaturate(&self, amount: f64) -> SolidColor {
+        self.saturate(-amount)
+    }
+
+    /// Turn the hue by `degrees` (negative turns the other way), wrapping
+    /// around 360°. Saturation, brightness and alpha are kept.
+    pub fn rotate_hue(&self, degrees: f64) -> SolidColor {
+        let (h, s, b) = self.to_hsb();
+        Self::from_hsb((h + degrees / 360.0).rem_euclid(1.0), s, b, self.a)
+    }
+
+    /// Linear-light RGB (sRGB transfer function removed), each 0.0–1.0.
+    pub fn to_linear_rgb(&self) -> (f64, f64, f64) {
+        (
+            math::srgb_to_linear(self.r),
+            math::srgb_to_linear(self.g),
+            math::srgb_to_linear(self.b),
+        )
+    }
+
+    /// CSS `rgb(R, G, B)` with 0–255 channels. Alpha is ignored.
+    pub fn to_rgb_string(&self) -> String {
+        let (r, g, b) = self.to_rgb();
+        format!("rgb({r}, {g}, {b})")
+    }
+
+    /// CSS `rgba(R, G, B, A)` with 0–255 channels and 0–1 alpha.
+    pub fn to_rgba_string(&self) -> String {
+        let (r, g, b) = self.to_rgb();
+        format!("rgba({r}, {g}, {b}, {})", format_unit(self.a))
+    }
+
+    /// CSS hex with `#`: `#RRGGBB`, or `#RRGGBBAA` when alpha < 1.0.
+    pub fn to_css_hex(&self) -> String {
+        format!("#{}", self.to_hex())
+    }
+
+    /// CSS `rgb(R G B)` in the space syntax with 0–255 channels;
+    /// `rgb(R G B / A)` when alpha < 1.0.
+    pub fn to_css_rgb(&self) -> String {
+        let (r, g, b) = self.to_rgb();
+        css_function("rgb", &format!("{r} {g} {b}"), self.a)
+    }
+
+    /// CSS `hsl(H S% L%)` in the space syntax with the hue in whole degrees;
+    /// `hsl(H S% L% / A)` when alpha < 1.0.
+    pub fn to_css_hsl(&self) -> String {
+        let (h, s, l) = self.to_hsl();
+        let pct = |v: f64| (v * 100.0).round() as i64;
+        css_function(
+            "hsl",
+            &format!("{} {}% {}%", degrees(h), pct(s), pct(l)),
+            self.a,
+        )
+    }
+
+    /// Figma plugin RGBA object, e.g.
+    /// `{ "r": 0.231, "g": 0.510, "b": 0.965, "a": 1 }`. Channels have three
+    /// decimals; alpha is shown like [`to_rgba_string`](Self::to_rgba_string)'s.
+    pub fn to_figma_string(&self) -> String {
+        format!(
+            "{{ \"r\": {:.3}, \"g\": {:.3}, \"b\": {:.3}, \"a\": {} }}",
+            self.r,
+            self.g,
+            self.b,
+            format_unit(self.a)
+        )
+    }
+
+    /// CSS `hsl(H, S%, L%)` with the hue in whole degrees. Alpha is ignored.
+    pub fn to_hsl_string(&self) -> String {
+        let (h, s, l) = self.to_hsl();
+        hue_function("hsl", &degrees(h), s, l, None)
+    }
+
+    /// CSS `hsla(H, S%, L%, A)` with the hue in whole degrees.
+    pub fn to_hsla_string(&self) -> String {
+        let (h, s, l) = self.to_hsl();
+        hue_function("hsla", &degrees(h), s, l, Some(self.a))
+    }
+
+    /// `hsb(H, S%, B%)` in the same shape as [`to_hsl_string`](Self::to_hsl_string).
+    /// HSB has no CSS syntax; this is for display and copying.
+    pub fn to_hsb_string(&self) -> String {
+        let (h, s, b) = self.to_hsb();
+        hue_function("hsb", &degrees(h), s, b, None)
+    }
+
+    /// `hsba(H, S%, B%, A)`, the alpha variant of [`to_hsb_string`](Self::to_hsb_string).
+    pub fn to_hsba_string(&self) -> String {
+        let (h, s, b) = self.to_hsb();
+        hue_function("hsba", &degrees(h), s, b, Some(self.a))
+    }
+
+    /// Create from OKLCH: lightness 0.0–1.0, chroma (about 0.0–0.37 for
+    /// sRGB), hue 0.0–1.0.
+    ///
+    /// Out-of-gamut input is mapped into sRGB by reducing chroma while
+    /// keeping lightness and hue, rather than clamping channels (which
+    /// shifts hue).
+    pub fn from_oklch(l: f64, c: f64, h: f64, a: f64) -> Self {
+        let (r, g, b) = math::oklch_to_srgb_in_gamut(l, c.max(0.0), h.rem_euclid(1.0));
+        Self::from_rgba(r, g, b, a)
+    }
+
+    /// Convert to OKLCH. Returns (L, C, h) with L and h in 0.0–1.0.
+    pub fn to_oklch(&self) -> (f64, f64, f64) {
+        let (l, a, b) = math::srgb_to_oklab(self.r, self.g, self.b);
+        math::oklab_to_oklch(l, a, b)
+    }
+
+    /// Perceived lightness: OKLab L, 0.0 (black) to 1.0 (white). Alpha is
+    /// ignored.
+    ///
+    /// Unlike HSL lightness, this tracks how light the color looks: pure
+    /// blue has HSL lightness 0.5 but OKLab L of about 0.45, pure yellow
+    /// 0.5 and about 0.97. Unlike WCAG relative luminance, equal steps in L
+    /// look like roughly equal steps in lightness.
+    pub fn perceived_lightness(&self) -> f64 {
+        math::srgb_to_oklab(self.r, self.g, self.b).0
+    }
+
+    /// WCAG 2 relative luminance (0.0 for black to 1.0 for white): linear
+    /// RGB weighted 0.2126, 0.7152 and 0.0722. Alpha is ignored.
+    pub fn relative_luminance(&self) -> f64 {
+        math::relative_luminance(self.r, self.g, self.b)
+    }
+
+    /// WCAG 2 contrast ratio between this color and `other`, from 1.0 (same
+    /// luminance) to 21.0 (black and white). The order of the two colors
+    /// doesn't matter, and alpha is ignored; see
+    /// [`contrast_against_checkerboard`](Self::contrast_against_checkerboard)
+    /// for translucent colors.
+    pub fn contrast_ratio(&self, other: &SolidColor) -> f64 {
+        math::contrast_ratio(self.relative_luminance(), other.relative_luminance())
+    }
+
+    /// CSS `oklch(L% C H)` with lightness as a percent, chroma to three
+    /// decimals and hue in degrees; `oklch(L% C H / A)` when translucent.
+    pub fn to_oklch_string(&self) -> String {
+        let (l, c, h) = self.to_oklch();
+        let tenths = |v: f64| (v * 10.0).round() / 10.0;
+        let lch = format!(
+            "{}% {} {}",
+            tenths(l * 100.0),
+            (c * 1000.0).round() / 1000.0,
+            tenths(h * 360.0) % 360.0
+        );
+        if self.a < 1.0 {
+            format!("oklch({lch} / {})", format_unit(self.a))
+        } else {
+            format!("oklch({lch})")
+        }
+    }
+
     /// Create from f64 RGBA. Values are clamped to 0.0–1.0.
     pub fn from_rgba(r: f64, g: f64, b: f64, a: f64) -> Self {
         Self {
@@ -166,6 +627,373 @@ impl SolidColor {
             a: a.clamp(0.0, 1.0),
         }
     }
+
+    /// Approximate color of a black body at `kelvin`, opaque, with the
+    /// brightest channel at full intensity. Uses Tanner Helland's curve fit;
+    /// `kelvin` is clamped to 1000–40000 K. 6600 K is close to white.
+    #[cfg(feature = "kelvin")]
+    pub fn from_kelvin(kelvin: f64) -> Self {
+        let t = kelvin.clamp(1000.0, 40000.0) / 100.0;
+        let r = if t <= 66.0 {
+            255.0
+        } else {
+            329.698_727_446 * (t - 60.0).powf(-0.133_204_759_2)
+        };
+        let g = if t <= 66.0 {
+            99.470_802_586_1 * t.ln() - 161.119_568_166_1
+        } else {
+            288.122_169_528_3 * (t - 60.0).powf(-0.075_514_849_2)
+        };
+        let b = if t >= 66.0 {
+            255.0
+        } else if t <= 19.0 {
+            0.0
+        } else {
+            138.517_731_223_1 * (t - 10.0).ln() - 305.044_792_730_7
+        };
+        let unit = |c: f64| (c / 255.0).clamp(0.0, 1.0);
+        Self::from_rgba(unit(r), unit(g), unit(b), 1.0)
+    }
+
+    /// [`from_kelvin`](Self::from_kelvin) with a tint axis, as on photo
+    /// white-balance controls.
+    ///
+    /// `tint` runs from -1.0 (green) through 0.0 (no shift, identical to
+    /// `from_kelvin`) to 1.0 (magenta), and is clamped to that range. The
+    /// shift scales green in linear light by up to ±30%, then renormalizes
+    /// so the brightest channel stays at full intensity.
+    #[cfg(feature = "kelvin")]
+    pub fn from_temperature_and_tint(kelvin: f64, tint: f64) -> Self {
+        let base = Self::from_kelvin(kelvin);
+        let tint = tint.clamp(-1.0, 1.0);
+        if tint == 0.0 {
+            return base;
+        }
+        let (r, g, b) = base.to_linear_rgb();
+        let g = g * (1.0 - 0.3 * tint);
+        let max = r.max(g).max(b);
+        let (r, g, b) = if max > 0.0 {
+            (r / max, g / max, b / max)
+        } else {
+            (r, g, b)
+        };
+        Self::from_rgba(
+            math::linear_to_srgb(r),
+            math::linear_to_srgb(g),
+            math::linear_to_srgb(b),
+            1.0,
+        )
+    }
+
+    /// Create from f64 RGBA, tone-mapping HDR channels above 1.0 into range
+    /// instead of clipping them.
+    ///
+    /// Uses extended Reinhard with the white point at the brightest channel,
+    /// so that channel maps to 1.0 and the others are compressed relative to
+    /// it, keeping a sense of relative brightness. Colors already within
+    /// 0.0–1.0 are unchanged. Lossy: the stored color is display-referred and
+    /// the original HDR values can't be recovered. Alpha is clamped.
+    pub fn from_rgba_tonemapped(r: f64, g: f64, b: f64, a: f64) -> Self {
+        let (r, g, b) = (r.max(0.0), g.max(0.0), b.max(0.0));
+        let white = r.max(g).max(b).max(1.0);
+        let map = |c: f64| c * (1.0 + c / (white * white)) / (1.0 + c);
+        Self::from_rgba(map(r), map(g), map(b), a)
+    }
+
+    /// Composite this color over `background` using source-over in sRGB.
+    ///
+    /// The result is opaque when `background` is opaque.
+    pub fn blend_over(&self, background: &SolidColor) -> SolidColor {
+        self.blend_over_in(background, BlendSpace::Srgb)
+    }
+
+    /// The color as it appears on screen when drawn over an opaque `panel`,
+    /// e.g. a translucent swatch over the picker's gray background. The
+    /// result is always opaque; `panel`'s own alpha is ignored.
+    ///
+    /// Contrast of a translucent color is only meaningful for this
+    /// composited appearance, not the straight-alpha channels.
+    pub fn composited_over_panel(&self, panel: SolidColor) -> SolidColor {
+        self.blend_over(&Self::from_rgba(panel.r, panel.g, panel.b, 1.0))
+    }
+
+    /// Worst-case contrast of `content` (e.g. text) drawn over this color
+    /// where it is shown on the alpha checkerboard: the lower of the WCAG
+    /// ratios over the light and the dark cells. Translucent `content` is
+    /// composited too.
+    ///
+    /// Equals the plain contrast ratio when this color is opaque.
+    pub fn contrast_against_checkerboard(&self, content: &SolidColor) -> f64 {
+        [constants::CHECKER_LIGHT, constants::CHECKER_DARK]
+            .map(|gray| {
+                let cell = SolidColor::from_rgb(gray, gray, gray);
+                let bg = self.blend_over(&cell);
+                content.blend_over(&bg).contrast_ratio(&bg)
+            })
+            .into_iter()
+            .fold(f64::INFINITY, f64::min)
+    }
+
+    /// APCA lightness contrast (Lc) of `text` on `background`, the
+    /// candidate contrast method for WCAG 3. Unlike the WCAG 2 ratio it is
+    /// signed and depends on polarity: about 106 for black on white, about
+    /// -108 for white on black, and 0.0 when the two are too close to read.
+    ///
+    /// Translucent `text` is composited over `background` first;
+    /// `background`'s own alpha is ignored.
+    pub fn apca_contrast(text: &SolidColor, background: &SolidColor) -> f64 {
+        let bg = Self::from_rgba(background.r, background.g, background.b, 1.0);
+        let fg = text.blend_over(&bg);
+        math::apca_lc(
+            math::apca_luminance(fg.r, fg.g, fg.b),
+            math::apca_luminance(bg.r, bg.g, bg.b),
+        )
+    }
+
+    /// Composite this color over `background` using source-over in `space`.
+    pub fn blend_over_in(&self, background: &SolidColor, space: BlendSpace) -> SolidColor {
+        let out_a = self.a + background.a * (1.0 - self.a);
+        if out_a <= 0.0 {
+            return Self::from_rgba(0.0, 0.0, 0.0, 0.0);
+        }
+        let (decode, encode): (Transfer, Transfer) = match space {
+            BlendSpace::Srgb => (|c| c, |c| c),
+            BlendSpace::Linear => (math::srgb_to_linear, math::linear_to_srgb),
+        };
+        let channel = |fg: f64, bg: f64| {
+            let c = (decode(fg) * self.a + decode(bg) * background.a * (1.0 - self.a)) / out_a;
+            encode(c)
+        };
+        Self::from_rgba(
+            channel(self.r, background.r),
+            channel(self.g, background.g),
+            channel(self.b, background.b),
+            out_a,
+        )
+    }
+
+    /// Composite `layers` over `background` with [`blend_over`](Self::blend_over),
+    /// bottom layer first: each layer is drawn over the result of the ones
+    /// before it. An empty stack returns `background`.
+    pub fn blend_stack(layers: &[SolidColor], background: SolidColor) -> SolidColor {
+        layers
+            .iter()
+            .fold(background, |below, layer| layer.blend_over(&below))
+    }
+
+    /// Interpolate linearly from this color (`t` = 0.0) to `other` (`t` =
+    /// 1.0) in gamma-encoded sRGB, alpha included. `t` is clamped to
+    /// 0.0–1.0, and the endpoints are returned exactly.
+    pub fn mix(&self, other: &SolidColor, t: f64) -> SolidColor {
+        let t = t.clamp(0.0, 1.0);
+        let lerp = |a: f64, b: f64| a * (1.0 - t) + b * t;
+        Self::from_rgba(
+            lerp(self.r, other.r),
+            lerp(self.g, other.g),
+            lerp(self.b, other.b),
+            lerp(self.a, other.a),
+        )
+    }
+
+    /// Like [`mix`](Self::mix), but interpolates in OKLab, so the steps of a
+    /// gradient look evenly spaced and the midpoint of two saturated colors
+    /// doesn't sag into a dark, muddy tone. Alpha still interpolates linearly.
+    pub fn mix_oklab(&self, other: &SolidColor, t: f64) -> SolidColor {
+        let t = t.clamp(0.0, 1.0);
+        let lerp = |a: f64, b: f64| a * (1.0 - t) + b * t;
+        let (l1, a1, b1) = math::srgb_to_oklab(self.r, self.g, self.b);
+        let (l2, a2, b2) = math::srgb_to_oklab(other.r, other.g, other.b);
+        let (r, g, b) = math::oklab_to_srgb(lerp(l1, l2), lerp(a1, a2), lerp(b1, b2));
+        Self::from_rgba(r, g, b, lerp(self.a, other.a))
+    }
+
+    /// Interpolate in HSB, moving hue along the shorter arc of the wheel
+    /// (so 0.9 → 0.1 passes through 0.0, not 0.5). Saturation, brightness,
+    /// and alpha interpolate linearly. `t` is clamped to 0.0–1.0.
+    ///
+    /// A gray endpoint has no meaningful hue, so it borrows the other
+    /// endpoint's hue instead of sweeping from red.
+    pub fn lerp_hue(&self, other: &SolidColor, t: f64) -> SolidColor {
+        let t = t.clamp(0.0, 1.0);
+        let (h1, s1, b1) = self.to_hsb();
+        let (h2, s2, b2) = other.to_hsb();
+        let h1 = if s1 == 0.0 { h2 } else { h1 };
+        let h2 = if s2 == 0.0 { h1 } else { h2 };
+        let mut dh = h2 - h1;
+        if dh > 0.5 {
+            dh -= 1.0;
+        } else if dh < -0.5 {
+            dh += 1.0;
+        }
+        let lerp = |a: f64, b: f64| a + (b - a) * t;
+        Self::from_hsb(
+            (h1 + dh * t).rem_euclid(1.0),
+            lerp(s1, s2),
+            lerp(b1, b2),
+            lerp(self.a, other.a),
+        )
+    }
+
+    /// Reduce each channel to `bits_per_channel` bits (1–8), e.g. 4 bits
+    /// gives 16 evenly spaced levels. Alpha is quantized separately with
+    /// the same depth. Values outside 1–8 are clamped into that range.
+    pub fn quantize(&self, bits_per_channel: u8) -> SolidColor {
+        let levels = ((1u32 << bits_per_channel.clamp(1, 8)) - 1) as f64;
+        let q = |c: f64| (c * levels).round() / levels;
+        Self::from_rgba(q(self.r), q(self.g), q(self.b), q(self.a))
+    }
+
+    /// Snap every channel, alpha included, to the nearest exact 8-bit value
+    /// (`n / 255`).
+    ///
+    /// A snapped color survives HSB/HSL round-trips with `to_rgb` and
+    /// `to_hex` unchanged, which keeps UI conversions from drifting by one
+    /// 8-bit step. The tradeoff is that any sub-8-bit precision is lost.
+    /// Same as `quantize(8)`.
+    pub fn with_srgb_rounding_stable(&self) -> SolidColor {
+        self.quantize(8)
+    }
+
+    /// Nudge OKLCH lightness by the smallest amount that reaches
+    /// `target_ratio` WCAG contrast against `background`, keeping chroma,
+    /// hue, and alpha.
+    ///
+    /// Tries both lighter and darker and returns the closer result. If
+    /// neither direction reaches the target, returns the attainable color
+    /// with the highest contrast.
+    pub fn adjust_for_contrast(&self, background: &SolidColor, target_ratio: f64) -> SolidColor {
+        let bg_lum = background.relative_luminance();
+        let ratio = |c: &SolidColor| math::contrast_ratio(c.relative_luminance(), bg_lum);
+        if ratio(self) >= target_ratio {
+            return *self;
+        }
+
+        let (l0, a0, b0) = math::srgb_to_oklab(self.r, self.g, self.b);
+        let (_, chroma, hue) = math::oklab_to_oklch(l0, a0, b0);
+        let at = |l: f64| {
+            let (l, a, b) = math::oklch_to_oklab(l, chroma, hue);
+            let (r, g, b) = math::oklab_to_srgb(l, a, b);
+            SolidColor::from_rgba(r, g, b, self.a)
+        };
+
+        // Per direction: (lightness delta, color) of the nearest passing color
+        let mut best: Option<(f64, SolidColor)> = None;
+        let mut fallback = *self;
+        for end in [1.0, 0.0] {
+            let end_color = at(end);
+            if ratio(&end_color) < target_ratio {
+                if ratio(&end_color) > ratio(&fallback) {
+                    fallback = end_color;
+                }
+                continue;
+            }
+            // Bisect for the passing lightness closest to the original
+            let (mut near, mut far) = (l0, end);
+            for _ in 0..32 {
+                let mid = (near + far) / 2.0;
+                if ratio(&at(mid)) >= target_ratio {
+                    far = mid;
+                } else {
+                    near = mid;
+                }
+            }
+            let delta = (far - l0).abs();
+            if best.is_none_or(|(d, _)| delta < d) {
+                best = Some((delta, at(far)));
+            }
+        }
+        best.map_or(fallback, |(_, c)| c)
+    }
+}
+
+/// Degrees (0–360) and percents (0–100) to normalized 0.0–1.0 values.
+/// Hue 360 stays 1.0 rather than wrapping; hue math treats it as 0.0.
+fn display_to_unit(h: u16, x: u8, y: u8, a: u8) -> (f64, f64, f64, f64) {
+    let pct = |v: u8| v.min(100) as f64 / 100.0;
+    (h.min(360) as f64 / 360.0, pct(x), pct(y), pct(a))
+}
+
+/// A CSS number or percent scaled to 0.0–1.0: percents are divided by 100,
+/// plain numbers by `full` (e.g. 255 for an rgb() channel).
+fn css_number(v: &str, full: f64) -> Option<f64> {
+    let (v, scale) = match v.strip_suffix('%') {
+        Some(pct) => (pct, 100.0),
+        None => (v, full),
+    };
+    let n: f64 = v.trim().parse().ok()?;
+    n.is_finite().then(|| (n / scale).clamp(0.0, 1.0))
+}
+
+/// Split the inside of a CSS color function into its three components and
+/// optional alpha. Comma and space separators are accepted; with commas the
+/// alpha may be a fourth value instead of following a slash.
+fn css_args(args: &str) -> Option<(Vec<&str>, Option<&str>)> {
+    let (components, alpha) = match args.split_once('/') {
+        Some((components, alpha)) => (components, Some(alpha.trim())),
+        None => (args, None),
+    };
+    let commas = components.contains(',');
+    let mut parts: Vec<&str> = if commas {
+        components.split(',').map(str::trim).collect()
+    } else {
+        components.split_whitespace().collect()
+    };
+    let alpha = match (alpha, parts.len()) {
+        (None, 4) if commas => parts.pop(),
+        (alpha, 3) => alpha,
+        _ => return None,
+    };
+    Some((parts, alpha))
+}
+
+/// A CSS percent (`"60%"`) as 0.0–1.0. Bare numbers are rejected.
+fn css_percent(v: &str) -> Option<f64> {
+    v.strip_suffix('%')?;
+    css_number(v, 100.0)
+}
+
+/// A CSS hue (`"210"`, `"210deg"`, `"0.5turn"`, `"200grad"`, `"3rad"`) as
+/// a normalized 0.0–1.0 hue.
+fn css_hue(v: &str) -> Option<f64> {
+    let (v, full) = [
+        ("deg", 360.0),
+        ("grad", 400.0),
+        ("rad", std::f64::consts::TAU),
+        ("turn", 1.0),
+    ]
+    .into_iter()
+    .find_map(|(unit, full)| v.strip_suffix(unit).map(|n| (n, full)))
+    .unwrap_or((v, 360.0));
+    let n: f64 = v.trim().parse().ok()?;
+    n.is_finite().then(|| (n / full).rem_euclid(1.0))
+}
+
+/// Whole degrees for a normalized 0.0–1.0 hue.
+fn degrees(hue: f64) -> String {
+    format!("{}", (hue * 360.0).round() as i64)
+}
+
+/// A 0.0–1.0 value rounded to three decimals, without trailing zeros.
+fn format_unit(v: f64) -> String {
+    format!("{}", (v * 1000.0).round() / 1000.0)
+}
+
+/// `name(components)`, or `name(components / alpha)` when alpha < 1.0.
+fn css_function(name: &str, components: &str, alpha: f64) -> String {
+    if alpha < 1.0 {
+        format!("{name}({components} / {})", format_unit(alpha))
+    } else {
+        format!("{name}({components})")
+    }
+}
+
+/// `name(hue, x%, y%[, alpha])` with `x`/`y` in 0.0–1.0 shown as whole percents.
+pub(crate) fn hue_function(name: &str, hue: &str, x: f64, y: f64, alpha: Option<f64>) -> String {
+    let (x, y) = ((x * 100.0).round() as i64, (y * 100.0).round() as i64);
+    match alpha {
+        Some(a) => format!("{name}({hue}, {x}%, {y}%, {})", format_unit(a)),
+        None => format!("{name}({hue}, {x}%, {y}%)"),
+    }
 }
 
 impl fmt::Display for SolidColor {
@@ -178,8 +1006,827 @@ impl fmt::Display for SolidColor {
 impl FromStr for SolidColor {
     type Err = String;
 
-    /// Parses a hex color string (with or without `#`, 3/6/8 hex chars).
+    /// Parses a hex color string (with or without `#`, 3/4/6/8 hex chars).
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         SolidColor::from_hex(s).ok_or_else(|| format!("invalid hex color: {s}"))
     }
 }
+
+/// Rounds each channel to 8 bits. Peniko colors are not premultiplied, so
+/// alpha carries over as is.
+impl From<SolidColor> for floem::peniko::Color {
+    fn from(c: SolidColor) -> Self {
+        Self::rgba(c.r, c.g, c.b, c.a)
+    }
+}
+
+/// Exact: channels become multiples of 1/255, as with
+/// [`from_rgb`](SolidColor::from_rgb).
+impl From<floem::peniko::Color> for SolidColor {
+    fn from(c: floem::peniko::Color) -> Self {
+        let [r, g, b, a] = [c.r, c.g, c.b, c.a].map(|v| v as f64 / 255.0);
+        Self { r, g, b, a }
+    }
+}
+
+/// Serializes as an 8-digit hex string (`"3B82F6FF"`, see
+/// [`to_hex8`](SolidColor::to_hex8)).
+#[cfg(feature = "serde")]
+impl serde::Serialize for SolidColor {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_hex8())
+    }
+}
+
+/// Deserializes from a hex string (anything [`from_hex`](SolidColor::from_hex)
+/// accepts) or an `{ "r", "g", "b", "a" }` object of 0.0–1.0 channels, where
+/// `a` is optional and defaults to 1.0.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for SolidColor {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(serde::Deserialize)]
+        #[serde(untagged)]
+        enum Repr {
+            Hex(String),
+            Channels {
+                r: f64,
+                g: f64,
+                b: f64,
+                #[serde(default = "opaque")]
+                a: f64,
+            },
+        }
+        fn opaque() -> f64 {
+            1.0
+        }
+
+        match Repr::deserialize(deserializer)? {
+            Repr::Hex(hex) => SolidColor::try_from_hex(&hex).map_err(serde::de::Error::custom),
+            Repr::Channels { r, g, b, a } => Ok(SolidColor::from_rgba(r, g, b, a)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_errors_name_the_problem() {
+        assert_eq!(
+            SolidColor::try_from_hex("#12345"),
+            Err(ColorParseError::BadLength)
+        );
+        assert_eq!(
+            SolidColor::try_from_hex("GG0000"),
+            Err(ColorParseError::NonHexDigit)
+        );
+        assert_eq!(
+            SolidColor::try_parse("#12"),
+            Err(ColorParseError::BadLength)
+        );
+        assert_eq!(
+            SolidColor::try_parse("#12G"),
+            Err(ColorParseError::NonHexDigit)
+        );
+        assert_eq!(
+            SolidColor::try_parse("notacolor"),
+            Err(ColorParseError::UnknownName)
+        );
+        assert_eq!(
+            SolidColor::try_parse("12-34"),
+            Err(ColorParseError::UnknownFormat)
+        );
+        assert_eq!(
+            SolidColor::try_parse(""),
+            Err(ColorParseError::UnknownFormat)
+        );
+        assert_eq!(
+            SolidColor::try_parse("rgb(1, 2)"),
+            Err(ColorParseError::UnknownFormat)
+        );
+    }
+
+    #[test]
+    fn option_parsers_wrap_the_result_ones() {
+        assert_eq!(SolidColor::from_hex("12345"), None);
+        assert_eq!(SolidColor::parse("notacolor"), None);
+        assert_eq!(
+            SolidColor::parse("red"),
+            Some(SolidColor::from_rgb(255, 0, 0))
+        );
+        assert_eq!(
+            SolidColor::try_parse("#3B82F6"),
+            Ok(SolidColor::from_rgb(0x3B, 0x82, 0xF6))
+        );
+    }
+
+    fn assert_close(a: f64, b: f64) {
+        assert!((a - b).abs() < 1e-9, "{a} != {b}");
+    }
+
+    #[test]
+    fn blend_over_opaque_backgrounds() {
+        let half_red = SolidColor::from_rgba(1.0, 0.0, 0.0, 0.5);
+        let over_black = half_red.blend_over(&SolidColor::from_rgb(0, 0, 0));
+        assert_eq!(over_black.rgba(), (0.5, 0.0, 0.0, 1.0));
+        let over_white = half_red.blend_over(&SolidColor::from_rgb(255, 255, 255));
+        assert_eq!(over_white.rgba(), (1.0, 0.5, 0.5, 1.0));
+    }
+
+    #[test]
+    fn blend_over_translucent_background() {
+        let half_red = SolidColor::from_rgba(1.0, 0.0, 0.0, 0.5);
+        let half_black = SolidColor::from_rgba(0.0, 0.0, 0.0, 0.5);
+        let (r, g, b, a) = half_red.blend_over(&half_black).rgba();
+        assert_close(a, 0.75);
+        assert_close(r, 0.5 / 0.75);
+        assert_eq!((g, b), (0.0, 0.0));
+        let clear = SolidColor::from_rgba(0.0, 0.0, 0.0, 0.0);
+        assert_eq!(clear.blend_over(&clear).a(), 0.0);
+    }
+
+    #[test]
+    fn blend_over_in_linear_light() {
+        let half_white = SolidColor::from_rgba(1.0, 1.0, 1.0, 0.5);
+        let black = SolidColor::from_rgb(0, 0, 0);
+        let linear = half_white.blend_over_in(&black, BlendSpace::Linear);
+        assert_close(linear.r(), math::linear_to_srgb(0.5));
+        assert_eq!(half_white.blend_over_in(&black, BlendSpace::Srgb).r(), 0.5);
+    }
+
+    #[test]
+    fn adjust_for_contrast_reaches_aa() {
+        let white = SolidColor::from_rgb(255, 255, 255);
+        for hex in ["808080", "4F9DFF", "E5A50A"] {
+            let fg = SolidColor::from_hex(hex).unwrap();
+            assert!(fg.contrast_ratio(&white) < constants::CONTRAST_AA);
+            let fixed = fg.adjust_for_contrast(&white, constants::CONTRAST_AA);
+            let ratio = fixed.contrast_ratio(&white);
+            // Minimal nudge: just over the target, not all the way to black
+            assert!(ratio >= constants::CONTRAST_AA, "{hex}: {ratio}");
+            assert!(ratio < constants::CONTRAST_AA + 0.1, "{hex}: {ratio}");
+        }
+    }
+
+    #[test]
+    fn adjust_for_contrast_keeps_passing_colors_and_alpha() {
+        let white = SolidColor::from_rgb(255, 255, 255);
+        let black = SolidColor::from_rgb(0, 0, 0);
+        assert_eq!(
+            black.adjust_for_contrast(&white, constants::CONTRAST_AA),
+            black
+        );
+        let translucent = SolidColor::from_rgba(0.5, 0.5, 0.5, 0.4);
+        let fixed = translucent.adjust_for_contrast(&white, constants::CONTRAST_AA);
+        assert_eq!(fixed.a(), 0.4);
+    }
+
+    #[test]
+    fn adjust_for_contrast_falls_back_to_the_best_attainable() {
+        // Nothing reaches 21:1 against mid-gray; black gets closest
+        let gray = SolidColor::from_rgb(128, 128, 128);
+        let best = gray.adjust_for_contrast(&gray, 21.0);
+        let black = SolidColor::from_rgb(0, 0, 0);
+        assert!((best.contrast_ratio(&gray) - black.contrast_ratio(&gray)).abs() < 0.01);
+    }
+
+    #[test]
+    fn quantize_to_one_bit_snaps_to_black_or_white() {
+        let c = SolidColor::from_rgba(0.3, 0.8, 0.49, 0.7);
+        assert_eq!(c.quantize(1).rgba(), (0.0, 1.0, 0.0, 1.0));
+    }
+
+    #[test]
+    fn quantize_to_four_bits() {
+        let c = SolidColor::from_rgba(59.0 / 255.0, 130.0 / 255.0, 246.0 / 255.0, 0.5);
+        let q = c.quantize(4);
+        // 16 levels: each channel is a multiple of 1/15 (0x11)
+        assert_eq!(q.to_hex(), "3388EE88");
+        assert_eq!(q.quantize(4), q);
+    }
+
+    #[test]
+    fn quantize_clamps_bit_depth() {
+        let c = SolidColor::from_rgba(0.3, 0.8, 0.49, 0.7);
+        assert_eq!(c.quantize(0), c.quantize(1));
+        assert_eq!(c.quantize(12), c.quantize(8));
+        assert_eq!(c.quantize(8), c.with_srgb_rounding_stable());
+    }
+
+    /// Distance between two hues around the wheel.
+    fn hue_distance(a: f64, b: f64) -> f64 {
+        let d = (a - b).rem_euclid(1.0);
+        d.min(1.0 - d)
+    }
+
+    #[test]
+    fn lerp_hue_wraps_through_red() {
+        let magenta = SolidColor::from_hsb(0.9, 1.0, 1.0, 1.0);
+        let orange = SolidColor::from_hsb(0.1, 1.0, 1.0, 1.0);
+        let mid = magenta.lerp_hue(&orange, 0.5);
+        assert!(hue_distance(mid.to_hsb().0, 0.0) < 1e-9);
+        let quarter = magenta.lerp_hue(&orange, 0.25);
+        assert!(hue_distance(quarter.to_hsb().0, 0.95) < 1e-9);
+        // And back the other way
+        let back = orange.lerp_hue(&magenta, 0.75);
+        assert!(hue_distance(back.to_hsb().0, 0.95) < 1e-9);
+    }
+
+    #[test]
+    fn lerp_hue_gray_endpoint_borrows_hue() {
+        let gray = SolidColor::from_hsb(0.0, 0.0, 0.5, 1.0);
+        let blue = SolidColor::from_hsb(0.6, 1.0, 1.0, 1.0);
+        let (h, s, b) = gray.lerp_hue(&blue, 0.5).to_hsb();
+        assert!(hue_distance(h, 0.6) < 1e-9);
+        assert!((s - 0.5).abs() < 1e-9);
+        assert!((b - 0.75).abs() < 1e-9);
+    }
+
+    /// Tailwind blue-500.
+    fn blue() -> SolidColor {
+        SolidColor::from_rgb(0x3B, 0x82, 0xF6)
+    }
+
+    #[test]
+    fn css_strings_for_a_known_color() {
+        assert_eq!(blue().to_rgb_string(), "rgb(59, 130, 246)");
+        assert_eq!(blue().to_hsl_string(), "hsl(217, 91%, 60%)");
+        assert_eq!(blue().to_hsb_string(), "hsb(217, 76%, 96%)");
+    }
+
+    #[test]
+    fn css_alpha_strings_for_a_known_color() {
+        let half = SolidColor::from_rgba(blue().r(), blue().g(), blue().b(), 0.5);
+        assert_eq!(half.to_rgba_string(), "rgba(59, 130, 246, 0.5)");
+        assert_eq!(half.to_hsla_string(), "hsla(217, 91%, 60%, 0.5)");
+        assert_eq!(half.to_hsba_string(), "hsba(217, 76%, 96%, 0.5)");
+        assert_eq!(blue().to_rgba_string(), "rgba(59, 130, 246, 1)");
+    }
+
+    #[test]
+    fn srgb_rounding_stable_survives_hsb_round_trips() {
+        for r in (0..=255).step_by(15) {
+            for g in (3..=255).step_by(21) {
+                for b in (7..=255).step_by(31) {
+                    let raw = SolidColor::from_rgba(
+                        r as f64 / 255.0 + 0.0013,
+                        g as f64 / 255.0 - 0.0011,
+                        b as f64 / 255.0,
+                        0.377,
+                    );
+                    let c = raw.with_srgb_rounding_stable();
+                    let (h, s, v) = c.to_hsb();
+                    let back = SolidColor::from_hsb(h, s, v, c.a());
+                    assert_eq!(back.to_rgb(), c.to_rgb());
+                    assert_eq!(back.to_hex(), c.to_hex());
+                    assert_eq!(c.with_srgb_rounding_stable(), c);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn approximately_gray() {
+        let eps = 0.01;
+        assert!(SolidColor::from_rgb(128, 128, 128).is_approximately_gray(eps));
+        // HSB saturation 0.001 / 0.5
+        assert!(SolidColor::from_rgba(0.5, 0.5, 0.499, 1.0).is_approximately_gray(eps));
+        // Dark colors carry no meaningful hue either
+        assert!(SolidColor::from_rgba(0.005, 0.0, 0.0, 1.0).is_approximately_gray(eps));
+        assert!(!SolidColor::from_rgba(0.5, 0.5, 0.45, 1.0).is_approximately_gray(eps));
+        assert!(!SolidColor::from_rgb(0x3B, 0x82, 0xF6).is_approximately_gray(eps));
+    }
+
+    #[test]
+    fn from_hsb_degrees_matches_the_float_path() {
+        let c = SolidColor::from_hsb_degrees(217, 91, 96, 100);
+        assert_eq!(c, SolidColor::from_hsb(217.0 / 360.0, 0.91, 0.96, 1.0));
+        assert_eq!(c.to_hex(), "166BF5");
+    }
+
+    #[test]
+    fn from_hsl_degrees_matches_the_float_path() {
+        let c = SolidColor::from_hsl_degrees(217, 91, 60, 50);
+        assert_eq!(c, SolidColor::from_hsl(217.0 / 360.0, 0.91, 0.6, 0.5));
+        assert_eq!(c.to_hex(), "3C83F680");
+    }
+
+    #[test]
+    fn degree_constructors_clamp() {
+        assert_eq!(
+            SolidColor::from_hsb_degrees(400, 150, 96, 200),
+            SolidColor::from_hsb_degrees(360, 100, 96, 100)
+        );
+        // 360° is red, like 0°
+        assert_eq!(
+            SolidColor::from_hsb_degrees(360, 100, 100, 100).to_hex(),
+            "FF0000"
+        );
+    }
+
+    #[test]
+    fn tonemap_keeps_relative_brightness_where_clamp_flattens() {
+        let clamped = SolidColor::from_rgba(2.0, 1.0, 0.5, 1.0);
+        assert_eq!((clamped.r(), clamped.g(), clamped.b()), (1.0, 1.0, 0.5));
+
+        let mapped = SolidColor::from_rgba_tonemapped(2.0, 1.0, 0.5, 1.0);
+        assert_close(mapped.r(), 1.0);
+        assert_close(mapped.g(), 0.625);
+        assert_close(mapped.b(), 0.375);
+        // Red stays brighter than green, unlike the clamped color
+        assert!(mapped.r() > mapped.g() && mapped.g() > mapped.b());
+    }
+
+    #[test]
+    fn tonemap_leaves_in_range_colors_alone() {
+        let c = SolidColor::from_rgba_tonemapped(0.2, 0.5, 1.0, 0.5);
+        for (got, want) in [(c.r(), 0.2), (c.g(), 0.5), (c.b(), 1.0), (c.a(), 0.5)] {
+            assert_close(got, want);
+        }
+    }
+
+    #[test]
+    fn url_fragment_round_trips() {
+        let c = SolidColor::from_rgba(59.0 / 255.0, 130.0 / 255.0, 246.0 / 255.0, 0.5);
+        let fragment = c.to_url_fragment();
+        assert_eq!(fragment, "#color=3B82F680");
+        assert_eq!(
+            SolidColor::from_url_fragment(&fragment).unwrap().to_hex8(),
+            "3B82F680"
+        );
+        let opaque = blue().to_url_fragment();
+        assert_eq!(opaque, "#color=3B82F6FF");
+        assert_eq!(SolidColor::from_url_fragment(&opaque), Some(blue()));
+    }
+
+    #[test]
+    fn url_fragment_finds_color_among_params() {
+        let c = SolidColor::from_url_fragment("tab=2&color=F00").unwrap();
+        assert_eq!(c.to_hex(), "FF0000");
+    }
+
+    #[test]
+    fn malformed_url_fragments_are_none() {
+        for fragment in [
+            "",
+            "#",
+            "#tab=2",
+            "#color=",
+            "#color=#3B82F6",
+            "#color=XYZ",
+            "#color=3B82F",
+        ] {
+            assert_eq!(
+                SolidColor::from_url_fragment(fragment),
+                None,
+                "{fragment:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn temperature_bias_of_representative_hues() {
+        let bias = |deg: f64| SolidColor::from_hsb(deg / 360.0, 1.0, 1.0, 1.0).temperature_bias();
+        assert_close(bias(30.0), 1.0);
+        assert_close(bias(210.0), -1.0);
+        assert_close(bias(0.0), (30.0_f64).to_radians().cos());
+        assert!(bias(60.0) > 0.5, "yellow is warm");
+        assert!(bias(180.0) < -0.5, "cyan is cool");
+        assert!(bias(120.0).abs() < 1e-9, "green is neutral");
+        assert!(bias(300.0).abs() < 1e-9, "magenta is neutral");
+    }
+
+    #[test]
+    fn temperature_bias_scales_with_saturation() {
+        assert_eq!(SolidColor::from_rgb(128, 128, 128).temperature_bias(), 0.0);
+        let muted = SolidColor::from_hsb(30.0 / 360.0, 0.5, 1.0, 1.0);
+        assert_close(muted.temperature_bias(), 0.5);
+    }
+
+    #[test]
+    fn composited_over_panel_is_opaque_and_ignores_panel_alpha() {
+        let faint = SolidColor::from_rgba(0.0, 0.0, 0.0, 0.2);
+        let panel = SolidColor::from_rgba(1.0, 1.0, 1.0, 0.1);
+        let shown = faint.composited_over_panel(panel);
+        assert_eq!(shown.a(), 1.0);
+        assert_eq!(shown.to_rgb(), (204, 204, 204));
+    }
+
+    #[test]
+    fn composited_contrast_is_lower_than_straight_alpha() {
+        let faint = SolidColor::from_rgba(0.0, 0.0, 0.0, 0.2);
+        let white = SolidColor::from_rgb(255, 255, 255);
+        // Straight alpha treats the faint black as solid black
+        assert_close(faint.contrast_ratio(&white), 21.0);
+        let shown = faint.composited_over_panel(white);
+        assert!(shown.contrast_ratio(&white) < 1.7);
+    }
+
+    #[test]
+    fn checkerboard_contrast_takes_the_worse_cell() {
+        let gray = SolidColor::from_rgba(0.5, 0.5, 0.5, 0.5);
+        let black = SolidColor::from_rgb(0, 0, 0);
+        let white = SolidColor::from_rgb(255, 255, 255);
+        let cell = |v: u8| gray.blend_over(&SolidColor::from_rgb(v, v, v));
+        let (light, dark) = (
+            cell(constants::CHECKER_LIGHT),
+            cell(constants::CHECKER_DARK),
+        );
+
+        // Black text is worst over the dark cell, white text over the light one
+        assert_close(
+            gray.contrast_against_checkerboard(&black),
+            black.contrast_ratio(&dark),
+        );
+        assert_close(
+            gray.contrast_against_checkerboard(&white),
+            white.contrast_ratio(&light),
+        );
+        assert!(black.contrast_ratio(&light) > black.contrast_ratio(&dark));
+    }
+
+    #[test]
+    fn checkerboard_contrast_of_opaque_color_is_plain_contrast() {
+        let black = SolidColor::from_rgb(0, 0, 0);
+        assert_close(
+            blue().contrast_against_checkerboard(&black),
+            blue().contrast_ratio(&black),
+        );
+    }
+
+    #[test]
+    fn rgba_u32_round_trips() {
+        for packed in [0x00000000, 0xFFFFFFFF, 0x3B82F6FF, 0x3B82F680, 0x12345678] {
+            assert_eq!(SolidColor::from_rgba_u32(packed).to_rgba_u32(), packed);
+            assert_eq!(SolidColor::from_argb_u32(packed).to_argb_u32(), packed);
+        }
+    }
+
+    #[test]
+    fn argb_u32_puts_alpha_in_the_high_byte() {
+        let c = SolidColor::from_rgba_u32(0x3B82F680);
+        assert_eq!(c.to_argb_u32(), 0x803B82F6);
+        assert_eq!(SolidColor::from_argb_u32(0x803B82F6), c);
+        assert_eq!(blue().to_rgba_u32(), 0x3B82F6FF);
+        assert_eq!(blue().to_argb_u32(), 0xFF3B82F6);
+    }
+
+    #[cfg(feature = "kelvin")]
+    #[test]
+    fn neutral_tint_equals_from_kelvin() {
+        for kelvin in [1500.0, 2700.0, 5000.0, 6500.0, 9000.0, 20000.0] {
+            assert_eq!(
+                SolidColor::from_temperature_and_tint(kelvin, 0.0),
+                SolidColor::from_kelvin(kelvin)
+            );
+        }
+    }
+
+    #[cfg(feature = "kelvin")]
+    #[test]
+    fn tint_shifts_between_green_and_magenta() {
+        let base = SolidColor::from_kelvin(6500.0);
+        let green = SolidColor::from_temperature_and_tint(6500.0, -1.0);
+        let magenta = SolidColor::from_temperature_and_tint(6500.0, 1.0);
+        assert!(green.g() >= base.g() && green.r() < base.r());
+        assert!(magenta.g() < base.g());
+        // Tint is clamped to -1.0–1.0
+        assert_eq!(SolidColor::from_temperature_and_tint(6500.0, 5.0), magenta);
+    }
+
+    #[test]
+    fn blend_stack_of_nothing_is_the_background() {
+        let bg = SolidColor::from_rgba(0.2, 0.4, 0.6, 0.8);
+        assert_eq!(SolidColor::blend_stack(&[], bg), bg);
+    }
+
+    #[test]
+    fn blend_stack_opaque_layer_covers_the_background() {
+        let white = SolidColor::from_rgb(255, 255, 255);
+        assert_eq!(SolidColor::blend_stack(&[blue()], white), blue());
+    }
+
+    #[test]
+    fn blend_stack_draws_bottom_layer_first() {
+        let white = SolidColor::from_rgb(255, 255, 255);
+        let red = SolidColor::from_rgba(1.0, 0.0, 0.0, 0.5);
+        let blue = SolidColor::from_rgba(0.0, 0.0, 1.0, 0.5);
+        let c = SolidColor::blend_stack(&[red, blue], white);
+        for (got, want) in [(c.r(), 0.5), (c.g(), 0.25), (c.b(), 0.75), (c.a(), 1.0)] {
+            assert_close(got, want);
+        }
+        assert_eq!(c, blue.blend_over(&red.blend_over(&white)));
+        assert_ne!(c, SolidColor::blend_stack(&[blue, red], white));
+    }
+
+    #[test]
+    fn arrays_are_in_rgba_order() {
+        let c = SolidColor::from_rgba(0.1, 0.2, 0.3, 0.4);
+        assert_eq!(c.to_array_f64(), [0.1, 0.2, 0.3, 0.4]);
+        assert_eq!(c.to_array(), [0.1_f32, 0.2, 0.3, 0.4]);
+    }
+
+    #[test]
+    fn arrays_round_trip() {
+        let c = SolidColor::from_rgba(0.1, 0.2, 0.3, 0.4);
+        assert_eq!(SolidColor::from_array_f64(c.to_array_f64()), c);
+        assert_eq!(SolidColor::from_array(blue().to_array()).to_hex(), "3B82F6");
+        let clamped = SolidColor::from_array([1.5, -0.5, 0.5, 2.0]);
+        assert_eq!(clamped.to_array(), [1.0, 0.0, 0.5, 1.0]);
+    }
+
+    #[test]
+    fn perceived_lightness_differs_from_hsl_lightness() {
+        let pure_blue = SolidColor::from_rgb(0, 0, 255);
+        let yellow = SolidColor::from_rgb(255, 255, 0);
+        // Same HSL lightness, very different perceived lightness
+        assert_close(pure_blue.to_hsl().2, 0.5);
+        assert_close(yellow.to_hsl().2, 0.5);
+        assert!((pure_blue.perceived_lightness() - 0.452).abs() < 0.001);
+        assert!((yellow.perceived_lightness() - 0.968).abs() < 0.001);
+        // #3B82F6: HSL lightness 0.60, OKLab L about 0.62
+        assert!((blue().perceived_lightness() - 0.623).abs() < 0.001);
+    }
+
+    #[test]
+    fn perceived_lightness_of_black_and_white() {
+        assert!(SolidColor::from_rgb(0, 0, 0).perceived_lightness().abs() < 1e-6);
+        assert!((SolidColor::from_rgb(255, 255, 255).perceived_lightness() - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn figma_string_is_exact() {
+        assert_eq!(
+            blue().to_figma_string(),
+            r#"{ "r": 0.231, "g": 0.510, "b": 0.965, "a": 1 }"#
+        );
+        let translucent = SolidColor::from_hex("3B82F680").unwrap();
+        assert_eq!(
+            translucent.to_figma_string(),
+            r#"{ "r": 0.231, "g": 0.510, "b": 0.965, "a": 0.502 }"#
+        );
+        assert_eq!(
+            SolidColor::from_rgba(0.0, 1.0, 0.0, 0.0).to_figma_string(),
+            r#"{ "r": 0.000, "g": 1.000, "b": 0.000, "a": 0 }"#
+        );
+    }
+
+    fn named_at(deg: f64) -> &'static str {
+        SolidColor::from_hsb(deg / 360.0, 1.0, 1.0, 1.0).hue_name()
+    }
+
+    #[test]
+    fn hue_name_bucket_boundaries() {
+        // Each bucket runs from 15° below its center to just under 15° above
+        for (i, name) in HUE_NAMES.iter().enumerate() {
+            let center = i as f64 * 30.0;
+            assert_eq!(named_at(center), *name);
+            assert_eq!(named_at(center + 14.9), *name);
+            assert_eq!(named_at((center - 14.9).rem_euclid(360.0)), *name);
+        }
+        assert_eq!(named_at(14.9), "red");
+        assert_eq!(named_at(15.1), "orange");
+        assert_eq!(named_at(344.9), "rose");
+        assert_eq!(named_at(345.1), "red");
+    }
+
+    #[test]
+    fn hue_name_gray_threshold() {
+        let at = |s, b| SolidColor::from_hsb(0.6, s, b, 1.0).hue_name();
+        assert_eq!(at(0.09, 1.0), "gray");
+        assert_eq!(at(1.0, 0.09), "gray");
+        assert_eq!(at(0.11, 1.0), "azure");
+        assert_eq!(at(1.0, 0.11), "azure");
+    }
+
+    #[test]
+    fn apca_matches_reference_values() {
+        let black = SolidColor::from_rgb(0, 0, 0);
+        let white = SolidColor::from_rgb(255, 255, 255);
+        let gray = SolidColor::from_rgb(0x88, 0x88, 0x88);
+        let lc = SolidColor::apca_contrast;
+        // Published APCA 0.0.98G values
+        assert!((lc(&black, &white) - 106.04).abs() < 0.01);
+        assert!((lc(&white, &black) + 107.88).abs() < 0.01);
+        assert!((lc(&gray, &white) - 63.06).abs() < 0.01);
+        assert!((lc(&white, &gray) + 68.54).abs() < 0.01);
+    }
+
+    #[test]
+    fn apca_is_zero_for_near_identical_colors() {
+        assert_eq!(SolidColor::apca_contrast(&blue(), &blue()), 0.0);
+        let a = SolidColor::from_rgb(0x80, 0x80, 0x80);
+        let b = SolidColor::from_rgb(0x84, 0x84, 0x84);
+        assert_eq!(SolidColor::apca_contrast(&a, &b), 0.0);
+    }
+
+    #[test]
+    fn cmyk_round_trips_primaries() {
+        for hex in [
+            "FF0000", "00FF00", "0000FF", "00FFFF", "FF00FF", "FFFF00", "000000", "FFFFFF",
+            "3B82F6",
+        ] {
+            let c = SolidColor::from_hex(hex).unwrap();
+            let (cy, m, y, k) = c.to_cmyk();
+            let back = SolidColor::from_cmyk(cy, m, y, k, 1.0);
+            for (got, want) in [(back.r(), c.r()), (back.g(), c.g()), (back.b(), c.b())] {
+                assert!((got - want).abs() < 0.004, "{hex}");
+            }
+        }
+    }
+
+    #[test]
+    fn cmyk_of_primaries() {
+        let cmyk = |hex| SolidColor::from_hex(hex).unwrap().to_cmyk();
+        assert_eq!(cmyk("FF0000"), (0.0, 1.0, 1.0, 0.0));
+        assert_eq!(cmyk("00FFFF"), (1.0, 0.0, 0.0, 0.0));
+        assert_eq!(cmyk("000000"), (0.0, 0.0, 0.0, 1.0));
+        assert_eq!(cmyk("FFFFFF"), (0.0, 0.0, 0.0, 0.0));
+    }
+
+    fn css_hex(s: &str) -> Option<String> {
+        SolidColor::from_css(s).map(|c| c.to_hex())
+    }
+
+    #[test]
+    fn css_hsl_hue_units() {
+        for hsl in [
+            "hsl(210, 100%, 60%)",
+            "hsl(210deg, 100%, 60%)",
+            "hsl(0.583333turn, 100%, 60%)",
+            "hsl(233.3333grad, 100%, 60%)",
+            "hsl(3.6651914rad, 100%, 60%)",
+            "hsl(-150deg, 100%, 60%)",
+        ] {
+            assert_eq!(css_hex(hsl).as_deref(), Some("3399FF"), "{hsl}");
+        }
+    }
+
+    #[test]
+    fn css_hsl_separators_and_alpha() {
+        assert_eq!(css_hex("hsl(210 100% 60%)").as_deref(), Some("3399FF"));
+        assert_eq!(
+            css_hex("HSL( 210 , 100% , 60% )").as_deref(),
+            Some("3399FF")
+        );
+        assert_eq!(
+            css_hex("hsla(210, 100%, 60%, 0.5)").as_deref(),
+            Some("3399FF80")
+        );
+        assert_eq!(
+            css_hex("hsl(210 100% 60% / 50%)").as_deref(),
+            Some("3399FF80")
+        );
+        assert_eq!(
+            css_hex("hsl(210 100% 60% / 0.5)").as_deref(),
+            Some("3399FF80")
+        );
+    }
+
+    #[test]
+    fn css_hwb() {
+        assert_eq!(css_hex("hwb(210 20% 10%)").as_deref(), Some("338CE6"));
+        assert_eq!(
+            css_hex("hwb(0.5833333turn 20% 10% / 50%)").as_deref(),
+            Some("338CE680")
+        );
+        // Whiteness + blackness of 100% or more is gray
+        assert_eq!(css_hex("hwb(210 60% 60%)").as_deref(), Some("808080"));
+    }
+
+    #[test]
+    fn malformed_css_hsl_and_hwb_are_none() {
+        for bad in [
+            "hsl(210, 100, 60%)",
+            "hsl(210, 100%)",
+            "hsl(210 100% 60% 50%)",
+            "hsl(210, 100% 60%)",
+            "hsl(abc, 100%, 60%)",
+            "hsl(210px, 100%, 60%)",
+            "hsl(210, 100%, 60%",
+            "hwb(210, 20, 10%)",
+            "hwb(210 20% 10% / x)",
+            "hsv(210, 100%, 60%)",
+        ] {
+            assert_eq!(SolidColor::from_css(bad), None, "{bad}");
+        }
+    }
+
+    #[test]
+    fn four_digit_hex_has_alpha() {
+        let c = SolidColor::from_hex("#F00A").unwrap();
+        assert_eq!(c.to_rgb(), (255, 0, 0));
+        assert_eq!(c.to_hex8(), "FF0000AA");
+        assert_eq!(SolidColor::from_hex("f00a"), Some(c));
+        assert_eq!(math::normalize_hex("F00A"), "FF0000AA");
+    }
+
+    #[test]
+    fn five_and_seven_digit_hex_are_rejected() {
+        for hex in ["F00A0", "#F00A0", "3B82F6F", "#3B82F6F"] {
+            assert_eq!(SolidColor::from_hex(hex), None, "{hex}");
+        }
+        assert_eq!(math::normalize_hex("F00A0"), "808080");
+        assert_eq!(math::normalize_hex("3B82F6F"), "808080");
+    }
+
+    #[test]
+    fn black_on_white_contrast_is_exactly_21() {
+        let black = SolidColor::from_rgb(0, 0, 0);
+        let white = SolidColor::from_rgb(255, 255, 255);
+        assert_eq!(black.relative_luminance(), 0.0);
+        assert_eq!(white.relative_luminance(), 1.0);
+        assert_eq!(black.contrast_ratio(&white), 21.0);
+        assert_eq!(white.contrast_ratio(&white), 1.0);
+    }
+
+    #[test]
+    fn contrast_ratio_is_symmetric() {
+        let colors = [
+            blue(),
+            SolidColor::from_rgb(0, 0, 0),
+            SolidColor::from_rgb(0xFA, 0xCC, 0x15),
+            SolidColor::from_rgb(0x77, 0x77, 0x77),
+        ];
+        for a in &colors {
+            for b in &colors {
+                assert_eq!(a.contrast_ratio(b), b.contrast_ratio(a));
+            }
+        }
+        // #777 on white is just under AA
+        let gray = SolidColor::from_rgb(0x77, 0x77, 0x77);
+        let ratio = gray.contrast_ratio(&SolidColor::from_rgb(255, 255, 255));
+        assert!((ratio - 4.48).abs() < 0.01, "{ratio}");
+    }
+
+    #[test]
+    fn mix_returns_the_endpoints() {
+        let red = SolidColor::from_rgba(1.0, 0.0, 0.0, 0.5);
+        assert_eq!(red.mix(&blue(), 0.0), red);
+        assert_eq!(red.mix(&blue(), 1.0), blue());
+        assert_eq!(red.mix(&blue(), -1.0), red);
+        assert_eq!(red.mix(&blue(), 2.0), blue());
+        assert_eq!(red.mix_oklab(&blue(), 0.0).to_hex8(), red.to_hex8());
+        assert_eq!(red.mix_oklab(&blue(), 1.0).to_hex8(), blue().to_hex8());
+    }
+
+    #[test]
+    fn oklab_midpoint_differs_from_srgb() {
+        let red = SolidColor::from_rgb(255, 0, 0);
+        let pure_blue = SolidColor::from_rgb(0, 0, 255);
+        let srgb = red.mix(&pure_blue, 0.5);
+        let oklab = red.mix_oklab(&pure_blue, 0.5);
+        assert_eq!(srgb.to_rgb(), (128, 0, 128));
+        let (r, g, b) = oklab.to_rgb();
+        assert!(r.abs_diff(140) <= 1 && g.abs_diff(83) <= 1 && b.abs_diff(162) <= 1);
+        // The sRGB midpoint sags darker than either end's average
+        assert!(srgb.perceived_lightness() < 0.43);
+        assert!(oklab.perceived_lightness() > 0.53);
+    }
+
+    #[test]
+    fn lighten_fully_gives_white() {
+        let white = blue().lighten(1.0);
+        assert_close(white.r, 1.0);
+        assert_close(white.g, 1.0);
+        assert_close(white.b, 1.0);
+        assert_eq!(white.a, blue().a);
+        let black = blue().darken(1.0);
+        assert_eq!(black.to_rgb(), (0, 0, 0));
+    }
+
+    #[test]
+    fn desaturate_fully_gives_gray_of_same_lightness() {
+        let gray = blue().desaturate(1.0);
+        assert_close(gray.r, gray.g);
+        assert_close(gray.g, gray.b);
+        assert_close(gray.to_hsl().2, blue().to_hsl().2);
+        assert_eq!(gray.a, blue().a);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trips_through_hex() {
+        let json = serde_json::to_string(&blue()).unwrap();
+        assert_eq!(json, "\"3B82F6FF\"");
+        let back: SolidColor = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.to_hex8(), blue().to_hex8());
+
+        let channels: SolidColor = serde_json::from_str(r#"{"r":1.0,"g":0.5,"b":0.0}"#).unwrap();
+        assert_eq!(channels, SolidColor::from_rgba(1.0, 0.5, 0.0, 1.0));
+        assert!(serde_json::from_str::<SolidColor>("\"#12345\"").is_err());
+    }
+
+    #[test]
+    fn peniko_color_round_trips() {
+        let c = floem::peniko::Color::rgba8(0x3B, 0x82, 0xF6, 0x80);
+        let solid = SolidColor::from(c);
+        assert_eq!(solid.to_hex8(), "3B82F680");
+        assert_eq!(floem::peniko::Color::from(solid), c);
+
+        let off_grid = SolidColor::from_rgba(0.5, 0.25, 0.75, 0.5);
+        let back = SolidColor::from(floem::peniko::Color::from(off_grid));
+        assert_eq!(back.to_hex8(), off_grid.to_hex8());
+    }
+}
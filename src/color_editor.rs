@@ -2,77 +2,348 @@
 //! alongside the color wheel, brightness slider, alpha slider, hex input,
 //! and color swatch.
 
-use std::cell::Cell;
+use std::path::Path;
 use std::rc::Rc;
 
 use floem::prelude::*;
-use floem::reactive::{RwSignal, SignalGet, SignalUpdate, create_effect};
+use floem::reactive::{RwSignal, SignalGet, SignalUpdate, batch, create_effect};
 
+use crate::animate;
 use crate::brightness_slider::brightness_slider;
-use crate::color::SolidColor;
+use crate::channel_bar::channel_bar;
+use crate::clipboard::SharedClipboard;
+use crate::color::{self, SolidColor};
 use crate::color_wheel::color_wheel;
+use crate::config::{
+    ChannelOrder, EditorLayout, GroundTruth, Labels, PickerConfig, ResponseCurve, RgbDisplay,
+    Steps, WheelMode,
+};
 use crate::constants;
-#[cfg(all(feature = "eyedropper", target_os = "macos"))]
+#[cfg(all(
+    feature = "eyedropper",
+    any(target_os = "macos", target_os = "linux", target_os = "windows")
+))]
 use crate::eyedropper::eyedropper_button;
+#[cfg(feature = "image")]
+use crate::heatmap::Heatmap;
+use crate::highlight::{Dimension, Highlight, dim_unless};
+use crate::history;
+use crate::icon_font;
 #[cfg(feature = "alpha")]
 use crate::inputs::alpha_input;
-use crate::inputs::{copy_button, hex_input, number_input};
+use crate::inputs::{
+    Validity, WindowBlur, color_chip, copy_button, copy_button_with_icon, hex_byte_input,
+    hex_input, icon_button, number_input,
+};
 use crate::math;
+use crate::palette;
+use crate::saturation_slider::saturation_slider;
+use crate::swatch::swatch;
+use crate::sync::sync_derived;
+use crate::theme::Theme;
 
 #[cfg(feature = "alpha")]
 use crate::alpha_slider::alpha_slider;
+#[cfg(feature = "alpha")]
+use crate::brightness_alpha_pad::brightness_alpha_pad;
 
-/// Creates a consolidated color editor with HSB, HSL, and RGB input rows.
-pub(crate) fn color_editor(color: RwSignal<SolidColor>) -> impl IntoView {
-    // HSB signals (ground-truth)
-    let h = RwSignal::new(0.0_f64);
-    let s = RwSignal::new(0.0_f64);
-    let b = RwSignal::new(1.0_f64);
-    let a = RwSignal::new(1.0_f64);
-    let hex = RwSignal::new("808080FF".to_string());
+// Tolerances for the sync effects below. All values are in normalized
+// 0.0–1.0 units. One 8-bit step is 1/255 ≈ 0.0039, and 8-bit rounding moves
+// a value by at most half that (≈ 0.002).
 
-    // HSL derived signals
-    let s_hsl = RwSignal::new(0.0_f64);
-    let l = RwSignal::new(0.5_f64);
+/// Saturation/brightness below which a color's hue is ignored and the
+/// previous hue is kept.
+const GRAY_EPS: f64 = 0.001;
 
-    // RGB derived signals
-    let r = RwSignal::new(0.5_f64);
-    let g = RwSignal::new(0.5_f64);
-    let bl = RwSignal::new(0.5_f64);
+/// Smallest channel difference treated as a real color change. Used in both
+/// directions between the HSB signals and `color`, so a write in one
+/// direction can never be seen as a fresh change by the other.
+const COLOR_EPS: f64 = 0.001;
 
-    // Non-reactive guards to break forward→back-sync cycles between color signals.
-    let hsl_from_hsb = Rc::new(Cell::new(false));
-    let rgb_from_hsb = Rc::new(Cell::new(false));
+/// How far `color` may be from the current HSB signals and still count as
+/// the same color. Above the 8-bit rounding error, so an external color that
+/// only differs by rounding (e.g. from a hex round-trip) keeps the current
+/// HSB, including the hue of grays.
+const HSB_MATCH_EPS: f64 = 0.005;
 
-    // Initialize from current color
-    {
-        let c = color.get_untracked();
-        let (ch, cs, cb) = c.to_hsb();
+/// Smallest channel difference from a hex edit treated as a change. Below
+/// one 8-bit step so every hex edit lands, above the rounding error so a hex
+/// string regenerated from `color` doesn't echo back. Applies to RGB and
+/// alpha alike.
+const HEX_EPS: f64 = 0.003;
+
+/// Tolerance for the HSL and RGB derived rows in [`sync_derived`]. The same
+/// value gates both directions, which keeps them from oscillating.
+const SYNC_EPS: f64 = 0.001;
+
+/// Tolerance for the linear RGB row. Finer than [`SYNC_EPS`] because one
+/// 8-bit step of a dark linear value is much smaller than 0.001.
+const LINEAR_SYNC_EPS: f64 = 0.0005;
+
+/// Lightness this close to black or white leaves HSL saturation undefined.
+const HSL_EXTREME_EPS: f64 = 0.0005;
+
+/// Hue change below which an allowed-hue snap is skipped.
+const HUE_SNAP_EPS: f64 = 0.0001;
+
+/// Smallest |temperature bias| that shows the warm or cool glyph.
+const TEMPERATURE_GLYPH_BIAS: f64 = 0.25;
+
+/// Temperature range of the white-balance row's slider, in kelvin.
+#[cfg(feature = "kelvin")]
+const WHITE_BALANCE_KELVIN: (f64, f64) = (2000.0, 10000.0);
+
+/// Largest dropped text file we try to parse as a color string.
+const MAX_DROPPED_TEXT: u64 = 1024;
+
+/// Center pixel of a dropped image, or `None` if the image is empty.
+#[cfg(feature = "image")]
+fn image_center_color(img: &image::RgbaImage) -> Option<SolidColor> {
+    if img.width() == 0 || img.height() == 0 {
+        return None;
+    }
+    let px = img.get_pixel(img.width() / 2, img.height() / 2);
+    Some(SolidColor::from_rgba(
+        px[0] as f64 / 255.0,
+        px[1] as f64 / 255.0,
+        px[2] as f64 / 255.0,
+        px[3] as f64 / 255.0,
+    ))
+}
+
+/// Read a dropped non-image file as text and parse it with
+/// [`SolidColor::parse`]. Returns `None` for unreadable, oversized or
+/// unrecognized files.
+fn color_from_text_file(path: &Path) -> Option<SolidColor> {
+    if std::fs::metadata(path).ok()?.len() > MAX_DROPPED_TEXT {
+        return None;
+    }
+    let text = std::fs::read_to_string(path).ok()?;
+    SolidColor::parse(text.trim())
+}
+
+/// Quick-pick strip of the macOS system color swatches.
+#[cfg(all(feature = "system-colors", target_os = "macos"))]
+fn system_colors_strip(color: RwSignal<SolidColor>, show: bool) -> impl IntoView {
+    let colors = if show {
+        crate::system_colors::system_colors()
+    } else {
+        Vec::new()
+    };
+    let empty_strip = colors.is_empty();
+    h_stack_from_iter(
+        colors
+            .into_iter()
+            .map(move |c| color_chip(c, move || color.set(c))),
+    )
+    .style(move |s| {
+        s.flex_wrap(floem::taffy::FlexWrap::Wrap)
+            .gap(3.0)
+            .margin_horiz(8.0)
+            .apply_if(empty_strip, |s| s.hide())
+    })
+}
+
+/// Recently used colors, newest first. Clicking a chip restores it.
+fn history_strip(
+    color: RwSignal<SolidColor>,
+    history: Option<RwSignal<Vec<SolidColor>>>,
+) -> impl IntoView {
+    dyn_stack(
+        move || history.map(|h| h.get()).unwrap_or_default(),
+        |c| c.to_hex(),
+        move |c| color_chip(c, move || color.set(c)),
+    )
+    .style(move |s| {
+        let empty = history.is_none_or(|h| h.get().is_empty());
+        s.flex_wrap(floem::taffy::FlexWrap::Wrap)
+            .gap(3.0)
+            .margin_horiz(8.0)
+            .apply_if(empty, |s| s.hide())
+    })
+}
+
+/// WCAG contrast ratio of `fg` composited over `bg`, with translucent
+/// backgrounds first composited over the panel as they are drawn.
+fn contrast_ratio(fg: &SolidColor, bg: &SolidColor) -> f64 {
+    let gray = constants::PANEL_GRAY;
+    let bg = bg.composited_over_panel(SolidColor::from_rgb(gray, gray, gray));
+    fg.composited_over_panel(bg).contrast_ratio(&bg)
+}
+
+/// APCA Lc of `fg` on `bg`, with translucent backgrounds first composited
+/// over the panel as they are drawn.
+fn apca_contrast(fg: &SolidColor, bg: &SolidColor) -> f64 {
+    let gray = constants::PANEL_GRAY;
+    let bg = bg.composited_over_panel(SolidColor::from_rgb(gray, gray, gray));
+    SolidColor::apca_contrast(fg, &bg)
+}
+
+/// WCAG result for a contrast `ratio`: "AAA", "AA" or "Fail", against the
+/// thresholds for large or normal text.
+fn wcag_level(ratio: f64, large: bool) -> &'static str {
+    let (aa, aaa) = if large {
+        (constants::CONTRAST_AA_LARGE, constants::CONTRAST_AAA_LARGE)
+    } else {
+        (constants::CONTRAST_AA, constants::CONTRAST_AAA)
+    };
+    if ratio >= aaa {
+        "AAA"
+    } else if ratio >= aa {
+        "AA"
+    } else {
+        "Fail"
+    }
+}
+
+/// Badge row with the WCAG contrast ratio of `color` on `background` and
+/// its AA/AAA result for normal and large text. Hidden without a background.
+fn contrast_badge(
+    color: RwSignal<SolidColor>,
+    background: Option<RwSignal<SolidColor>>,
+) -> impl IntoView {
+    let ratio = move || {
+        background
+            .map(|bg| contrast_ratio(&color.get(), &bg.get()))
+            .unwrap_or(1.0)
+    };
+    let badge = move |caption: &'static str, large: bool| {
+        label(move || format!("{caption} {}", wcag_level(ratio(), large))).style(move |s| {
+            let pass = wcag_level(ratio(), large) != "Fail";
+            let (fg, bg) = if pass {
+                (Color::rgb8(22, 101, 52), Color::rgb8(220, 252, 231))
+            } else {
+                (Color::rgb8(153, 27, 27), Color::rgb8(254, 226, 226))
+            };
+            s.font_size(constants::LABEL_FONT)
+                .color(fg)
+                .background(bg)
+                .padding_horiz(4.0)
+                .padding_vert(1.0)
+                .border_radius(3.0)
+        })
+    };
+    h_stack((
+        label(move || format!("{:.2}:1", ratio())).style(|s| {
+            s.font_size(constants::LABEL_FONT + 1.0)
+                .font_family("monospace".to_string())
+                .color(Color::rgb8(84, 84, 84))
+        }),
+        badge("Text", false),
+        badge("Large", true),
+    ))
+    .style(move |s| {
+        s.items_center()
+            .justify_center()
+            .gap(4.0)
+            .apply_if(background.is_none(), |s| s.hide())
+    })
+}
+
+/// Text of the contrast readout next to the background swatch: the WCAG
+/// ratio of `fg` on `bg`, plus the APCA Lc with `show_apca`.
+fn contrast_readout(fg: &SolidColor, bg: &SolidColor, show_apca: bool) -> String {
+    let ratio = contrast_ratio(fg, bg);
+    if show_apca {
+        format!("{ratio:.1}:1 Lc {:.0}", apca_contrast(fg, bg))
+    } else {
+        format!("{ratio:.1}:1")
+    }
+}
+
+/// Keep `[h, s, b]` in sync with `color` in both directions. Grays keep the
+/// previous hue, and a `color` matching the signals within rounding leaves
+/// them unchanged.
+fn link_hsb(color: RwSignal<SolidColor>, [h, s, b]: [RwSignal<f64>; 3]) {
+    create_effect(move |_| {
+        let c = color.get();
+        let (eh, es, eb) = (h.get_untracked(), s.get_untracked(), b.get_untracked());
+        let (er, eg, ebl) = math::hsb_to_rgb(eh, es, eb);
+        if (er - c.r()).abs() < HSB_MATCH_EPS
+            && (eg - c.g()).abs() < HSB_MATCH_EPS
+            && (ebl - c.b()).abs() < HSB_MATCH_EPS
+        {
+            return;
+        }
+        let (nh, ns, nb) = c.to_hsb();
+        batch(|| {
+            if !c.is_approximately_gray(GRAY_EPS) {
+                h.set(nh);
+            }
+            s.set(ns);
+            b.set(nb);
+        });
+    });
+    create_effect(move |_| {
+        let (hv, sv, bv) = (h.get(), s.get(), b.get());
+        let current = color.get_untracked();
+        let next = SolidColor::from_hsb(hv, sv, bv, current.a());
+        if (next.r() - current.r()).abs() > COLOR_EPS
+            || (next.g() - current.g()).abs() > COLOR_EPS
+            || (next.b() - current.b()).abs() > COLOR_EPS
+        {
+            color.set(next);
+        }
+    });
+}
+
+/// `c` in the ground-truth model: `[h, s, b]` for HSB, `[h, s_hsl, l]`
+/// for HSL.
+fn ground_truth_of(truth: GroundTruth, c: &SolidColor) -> [f64; 3] {
+    let (hv, x, y) = match truth {
+        GroundTruth::Hsb => c.to_hsb(),
+        GroundTruth::Hsl => c.to_hsl(),
+    };
+    [hv, x, y]
+}
+
+/// sRGB of ground-truth values, as laid out by [`ground_truth_of`].
+fn ground_truth_rgb(truth: GroundTruth, [hv, x, y]: [f64; 3]) -> (f64, f64, f64) {
+    match truth {
+        GroundTruth::Hsb => math::hsb_to_rgb(hv, x, y),
+        GroundTruth::Hsl => {
+            let (_, sv, bv) = math::hsl_to_hsb(hv, x, y);
+            math::hsb_to_rgb(hv, sv, bv)
+        }
+    }
+}
+
+/// Write `c` to the ground-truth `signals`. Grays keep the previous hue, and
+/// in HSL black and white keep the previous saturation, as neither is
+/// defined there. Call inside a [`batch`].
+fn set_ground_truth(truth: GroundTruth, [h, x, y]: [RwSignal<f64>; 3], c: &SolidColor) {
+    let [ch, cx, cy] = ground_truth_of(truth, c);
+    if !c.is_approximately_gray(GRAY_EPS) {
         h.set(ch);
-        s.set(cs);
-        b.set(cb);
-        a.set(c.a());
-        hex.set(c.to_hex());
-        let (_, sh, lv) = math::hsb_to_hsl(ch, cs, cb);
-        s_hsl.set(sh);
-        l.set(lv);
-        r.set(c.r());
-        g.set(c.g());
-        bl.set(c.b());
     }
+    let extreme = cy <= HSL_EXTREME_EPS || cy >= 1.0 - HSL_EXTREME_EPS;
+    if truth == GroundTruth::Hsb || !extreme {
+        x.set(cx);
+    }
+    y.set(cy);
+}
 
-    // ── HSB → color (when any HSB component changes) ───────────────────
+/// Keep `color` and `hex` in sync with the ground-truth `signals` (see
+/// [`ground_truth_of`]) and alpha `a`. Edits of the signals set the color;
+/// external colors and committed hex values are written to the signals
+/// directly, so the ground truth never round-trips through the other model.
+fn link_ground_truth(
+    color: RwSignal<SolidColor>,
+    hex: RwSignal<String>,
+    a: RwSignal<f64>,
+    truth: GroundTruth,
+    signals: [RwSignal<f64>; 3],
+) {
+    // ── Ground truth → color (when any component changes) ──────────────
     create_effect(move |_| {
-        let hv = h.get();
-        let sv = s.get();
-        let bv = b.get();
+        let (nr, ng, nb) = ground_truth_rgb(truth, signals.map(|sig| sig.get()));
         let av = a.get();
-        let new_color = SolidColor::from_hsb(hv, sv, bv, av);
+        let new_color = SolidColor::from_rgba(nr, ng, nb, av);
         let current = color.get_untracked();
-        if (new_color.r() - current.r()).abs() > 0.001
-            || (new_color.g() - current.g()).abs() > 0.001
-            || (new_color.b() - current.b()).abs() > 0.001
-            || (new_color.a() - current.a()).abs() > 0.001
+        if (new_color.r() - current.r()).abs() > COLOR_EPS
+            || (new_color.g() - current.g()).abs() > COLOR_EPS
+            || (new_color.b() - current.b()).abs() > COLOR_EPS
+            || (new_color.a() - current.a()).abs() > COLOR_EPS
         {
             color.set(new_color);
             let new_hex = new_color.to_hex();
@@ -82,23 +353,22 @@ pub(crate) fn color_editor(color: RwSignal<SolidColor>) -> impl IntoView {
         }
     });
 
-    // External color -> HSB
+    // External color -> ground truth
     create_effect(move |prev: Option<SolidColor>| {
         let c = color.get();
         if let Some(prev) = prev
-            && (c.r() - prev.r()).abs() < 0.001
-            && (c.g() - prev.g()).abs() < 0.001
-            && (c.b() - prev.b()).abs() < 0.001
-            && (c.a() - prev.a()).abs() < 0.001
+            && (c.r() - prev.r()).abs() < COLOR_EPS
+            && (c.g() - prev.g()).abs() < COLOR_EPS
+            && (c.b() - prev.b()).abs() < COLOR_EPS
+            && (c.a() - prev.a()).abs() < COLOR_EPS
         {
             return c;
         }
-        let (er, eg, eb) =
-            math::hsb_to_rgb(h.get_untracked(), s.get_untracked(), b.get_untracked());
-        if (er - c.r()).abs() < 0.005
-            && (eg - c.g()).abs() < 0.005
-            && (eb - c.b()).abs() < 0.005
-            && (a.get_untracked() - c.a()).abs() < 0.005
+        let (er, eg, eb) = ground_truth_rgb(truth, signals.map(|sig| sig.get_untracked()));
+        if (er - c.r()).abs() < HSB_MATCH_EPS
+            && (eg - c.g()).abs() < HSB_MATCH_EPS
+            && (eb - c.b()).abs() < HSB_MATCH_EPS
+            && (a.get_untracked() - c.a()).abs() < HSB_MATCH_EPS
         {
             let new_hex = c.to_hex();
             if hex.get_untracked() != new_hex {
@@ -106,13 +376,13 @@ pub(crate) fn color_editor(color: RwSignal<SolidColor>) -> impl IntoView {
             }
             return c;
         }
-        let (ch, cs, cb) = c.to_hsb();
-        if cs > 0.001 && cb > 0.001 {
-            h.set(ch);
-        }
-        s.set(cs);
-        b.set(cb);
-        a.set(c.a());
+        // Batched so ground truth -> color sees only the final values, which
+        // match `c`; it then leaves `c` untouched, keeping exact 8-bit
+        // channels (e.g. from a hex) instead of rewriting them from floats
+        batch(|| {
+            set_ground_truth(truth, signals, &c);
+            a.set(c.a());
+        });
         let new_hex = c.to_hex();
         if hex.get_untracked() != new_hex {
             hex.set(new_hex);
@@ -125,10 +395,10 @@ pub(crate) fn color_editor(color: RwSignal<SolidColor>) -> impl IntoView {
         let hx = hex.get();
         if let Some(c) = SolidColor::from_hex(&hx) {
             let current = color.get_untracked();
-            let rgb_changed = (c.r() - current.r()).abs() > 0.003
-                || (c.g() - current.g()).abs() > 0.003
-                || (c.b() - current.b()).abs() > 0.003;
-            let alpha_changed = (c.a() - a.get_untracked()).abs() > 0.004;
+            let rgb_changed = (c.r() - current.r()).abs() > HEX_EPS
+                || (c.g() - current.g()).abs() > HEX_EPS
+                || (c.b() - current.b()).abs() > HEX_EPS;
+            let alpha_changed = (c.a() - a.get_untracked()).abs() > HEX_EPS;
             if rgb_changed || alpha_changed {
                 let new_a = if alpha_changed {
                     c.a()
@@ -137,187 +407,1520 @@ pub(crate) fn color_editor(color: RwSignal<SolidColor>) -> impl IntoView {
                 };
                 let new_color = SolidColor::from_rgba(c.r(), c.g(), c.b(), new_a);
                 color.set(new_color);
-                let (ch, cs, cb) = new_color.to_hsb();
-                if cs > 0.001 && cb > 0.001 {
-                    h.set(ch);
-                }
-                s.set(cs);
-                b.set(cb);
-                if alpha_changed {
-                    a.set(new_a);
-                }
+                batch(|| {
+                    set_ground_truth(truth, signals, &new_color);
+                    if alpha_changed {
+                        a.set(new_a);
+                    }
+                });
             }
         }
     });
+}
 
-    // HSB -> HSL display sync
-    let hsl_guard_fwd = hsl_from_hsb.clone();
-    create_effect(move |_| {
-        let hv = h.get();
-        let sv = s.get();
-        let bv = b.get();
-        let (_, new_s_hsl, new_l) = math::hsb_to_hsl(hv, sv, bv);
-        if (s_hsl.get_untracked() - new_s_hsl).abs() > 0.001
-            || (l.get_untracked() - new_l).abs() > 0.001
-        {
-            hsl_guard_fwd.set(true);
-            s_hsl.set(new_s_hsl);
-            l.set(new_l);
-            hsl_guard_fwd.set(false);
+/// Popover editing the contrast background `bg`: a hue/saturation wheel,
+/// a brightness slider and a hex field bound to `bg_hex`. Shown while
+/// `open` is set, floating over the rows below the swatch.
+#[allow(clippy::too_many_arguments)]
+fn background_popover(
+    bg: RwSignal<SolidColor>,
+    bg_hex: RwSignal<String>,
+    open: RwSignal<bool>,
+    theme: Theme,
+    steps: Steps,
+    validity: Validity,
+    blur: WindowBlur,
+    complete_names: bool,
+    labels: Labels,
+) -> impl IntoView {
+    let (h, s, b) = bg.get_untracked().to_hsb();
+    let hsb = [RwSignal::new(h), RwSignal::new(s), RwSignal::new(b)];
+    link_hsb(bg, hsb);
+    let [h, s, b] = hsb;
+    v_stack((
+        label(move || labels.background).style(|s| {
+            s.font_size(constants::LABEL_FONT + 1.0)
+                .color(Color::rgb8(84, 84, 84))
+        }),
+        color_wheel(h, s, b, None, theme, steps, WheelMode::Hsb, false, false),
+        brightness_slider(
+            h,
+            s,
+            b,
+            Vec::new(),
+            theme,
+            steps.slider,
+            None,
+            ResponseCurve::default(),
+        ),
+        hex_input(
+            bg_hex,
+            steps.slider,
+            validity,
+            blur,
+            complete_names,
+            labels.hex,
+        ),
+    ))
+    .style(move |st| {
+        st.absolute()
+            .inset_top(0.0)
+            .inset_left(8.0)
+            .z_index(10)
+            .items_center()
+            .gap(6.0)
+            .padding(8.0)
+            .background(Color::WHITE)
+            .border(1.0)
+            .border_color(Color::rgb8(200, 200, 200))
+            .border_radius(constants::RADIUS)
+            .box_shadow_blur(8.0)
+            .box_shadow_color(Color::rgba8(0, 0, 0, 40))
+            .apply_if(!open.get(), |s| s.hide())
+    })
+}
+
+/// HSL saturation and lightness of `[h, s, b]`.
+fn hsl_of([hv, sv, bv]: [f64; 3]) -> [f64; 2] {
+    let (_, sh, lv) = math::hsb_to_hsl(hv, sv, bv);
+    [sh, lv]
+}
+
+/// HSB from HSL saturation and lightness, keeping the hue of `[h, _, _]`.
+fn hsb_of([sh, lv]: [f64; 2], [hv, _, _]: [f64; 3]) -> [f64; 3] {
+    let (_, new_s, new_b) = math::hsl_to_hsb(hv, sh, lv);
+    [hv, new_s, new_b]
+}
+
+/// HSB saturation and brightness of `[h, s_hsl, l]`.
+fn hsb_sb_of([hv, sh, lv]: [f64; 3]) -> [f64; 2] {
+    let (_, sv, bv) = math::hsl_to_hsb(hv, sh, lv);
+    [sv, bv]
+}
+
+/// HSL from HSB saturation and brightness, keeping the hue of `[h, _, _]`.
+/// HSL saturation is kept at black and white, where it is undefined.
+fn hsl_from_sb([sv, bv]: [f64; 2], [hv, sh, _]: [f64; 3]) -> [f64; 3] {
+    let (_, new_s, new_l) = math::hsb_to_hsl(hv, sv, bv);
+    let new_s = if new_l > HSL_EXTREME_EPS && new_l < 1.0 - HSL_EXTREME_EPS {
+        new_s
+    } else {
+        sh
+    };
+    [hv, new_s, new_l]
+}
+
+/// Color set by the white-balance row: the white point at `kelvin` and
+/// `tint`, with `alpha`.
+#[cfg(feature = "kelvin")]
+fn white_balance_color(kelvin: f64, tint: f64, alpha: f64) -> SolidColor {
+    let c = SolidColor::from_temperature_and_tint(kelvin, tint);
+    SolidColor::from_rgba(c.r(), c.g(), c.b(), alpha)
+}
+
+/// A captioned slider editing `value` across `range`, with the value shown
+/// through `format`.
+#[cfg(feature = "kelvin")]
+fn labeled_slider(
+    caption: &'static str,
+    value: RwSignal<f64>,
+    (lo, hi): (f64, f64),
+    format: fn(f64) -> String,
+) -> impl IntoView {
+    use floem::unit::Pct;
+    use floem::views::slider::slider;
+
+    h_stack((
+        label(move || caption).style(|s| {
+            s.font_size(constants::LABEL_FONT)
+                .color(Color::rgb8(84, 84, 84))
+                .width(28.0)
+        }),
+        slider(move || Pct((value.get() - lo) / (hi - lo) * 100.0))
+            .on_change_pct(move |pct| value.set(lo + pct.0 / 100.0 * (hi - lo)))
+            .style(|s| s.flex_grow(1.0)),
+        label(move || format(value.get())).style(|s| {
+            s.font_size(constants::LABEL_FONT)
+                .font_family("monospace".to_string())
+                .color(Color::rgb8(84, 84, 84))
+                .width(44.0)
+        }),
+    ))
+    .style(|s| s.items_center().gap(6.0))
+}
+
+/// Temperature and tint sliders that set `color` to
+/// [`white_balance_color`], keeping its alpha. The sliders only write:
+/// editing the color elsewhere leaves them where they are.
+#[cfg(feature = "kelvin")]
+fn white_balance_row(
+    color: RwSignal<SolidColor>,
+    labels: [&'static str; 2],
+    show: bool,
+) -> impl IntoView {
+    let kelvin = RwSignal::new(6500.0);
+    let tint = RwSignal::new(0.0);
+    create_effect(move |prev: Option<()>| {
+        let (k, t) = (kelvin.get(), tint.get());
+        // Leave the initial color alone until a slider moves
+        if prev.is_some() {
+            color.update(|c| *c = white_balance_color(k, t, c.a()));
         }
     });
+    v_stack((
+        labeled_slider(labels[0], kelvin, WHITE_BALANCE_KELVIN, |k| {
+            format!("{:.0}K", k)
+        }),
+        labeled_slider(labels[1], tint, (-1.0, 1.0), |t| format!("{:+.2}", t)),
+    ))
+    .style(move |s| s.gap(4.0).margin_horiz(8.0).apply_if(!show, |s| s.hide()))
+}
 
-    // ── HSL → HSB back-sync (when HSL inputs change) ───────────────────
-    let hsl_guard_back = hsl_from_hsb;
+/// Reset `color`'s alpha to 1.0 whenever anything makes it translucent.
+fn pin_opaque(color: RwSignal<SolidColor>) {
     create_effect(move |_| {
-        let sh = s_hsl.get();
-        let lv = l.get();
-        if hsl_guard_back.get() {
-            return;
+        let c = color.get();
+        if c.a() != 1.0 {
+            color.set(SolidColor::from_rgba(c.r(), c.g(), c.b(), 1.0));
+        }
+    });
+}
+
+/// Linear-light channels of sRGB `[r, g, b]`.
+fn linear_of(rgb: [f64; 3]) -> [f64; 3] {
+    rgb.map(math::srgb_to_linear)
+}
+
+/// sRGB channels of linear-light `[r, g, b]`.
+fn srgb_of(linear: [f64; 3], _: [f64; 3]) -> [f64; 3] {
+    linear.map(math::linear_to_srgb)
+}
+
+/// RGB row labels when none are configured: "sR" hints that the fields are
+/// gamma-encoded sRGB, while linear channels are labeled plainly.
+fn default_rgb_labels(display: RgbDisplay) -> [&'static str; 3] {
+    match display {
+        RgbDisplay::Srgb => ["sR", "G", "B"],
+        RgbDisplay::Linear => ["R", "G", "B"],
+    }
+}
+
+/// Field labels of the HSB, HSL and RGB rows, from [`PickerConfig::labels`]
+/// and [`PickerConfig::rgb_labels`].
+fn row_labels(config: &PickerConfig) -> [[&'static str; 3]; 3] {
+    let labels = config.labels;
+    [
+        [labels.hue, labels.saturation, labels.brightness],
+        [labels.hue, labels.saturation, labels.lightness],
+        config
+            .rgb_labels
+            .unwrap_or_else(|| default_rgb_labels(config.rgb_display)),
+    ]
+}
+
+/// Text copied by the CMYK row's copy button: whole percents, e.g.
+/// `76, 47, 0, 4`.
+#[cfg(feature = "cmyk")]
+fn cmyk_copy_text(cmyk: [f64; 4]) -> String {
+    let [c, m, y, k] = cmyk.map(|v| (v * 100.0).round() as i64);
+    format!("{c}, {m}, {y}, {k}")
+}
+
+/// Text copied by the RGB row's copy button. RGB order copies CSS
+/// (`rgb(…)`, or `color(srgb-linear …)` for linear display); BGR order
+/// copies the 0–255 values as shown, e.g. `246, 130, 59`.
+fn rgb_copy_text(c: &SolidColor, order: ChannelOrder, display: RgbDisplay) -> String {
+    match (order, display) {
+        (ChannelOrder::Bgr, RgbDisplay::Srgb) => {
+            let (r, g, b) = c.to_rgb();
+            format!("{b}, {g}, {r}")
         }
-        let hv = h.get_untracked();
-        let (_, new_s_hsb, new_b) = math::hsl_to_hsb(hv, sh, lv);
-        if (s.get_untracked() - new_s_hsb).abs() > 0.001 {
-            s.set(new_s_hsb);
+        (ChannelOrder::Bgr, RgbDisplay::Linear) => {
+            let (lr, lg, lb) = c.to_linear_rgb();
+            let [r, g, b] = [lr, lg, lb].map(|v| (v * 255.0).round() as u8);
+            format!("{b}, {g}, {r}")
         }
-        if (b.get_untracked() - new_b).abs() > 0.001 {
-            b.set(new_b);
+        (ChannelOrder::Rgb, RgbDisplay::Srgb) => c.to_rgb_string(),
+        (ChannelOrder::Rgb, RgbDisplay::Linear) => {
+            let (lr, lg, lb) = c.to_linear_rgb();
+            format!("color(srgb-linear {lr:.4} {lg:.4} {lb:.4})")
         }
-    });
+    }
+}
 
-    // HSB -> RGB display sync
-    let rgb_guard_fwd = rgb_from_hsb.clone();
-    create_effect(move |_| {
-        let hv = h.get();
-        let sv = s.get();
-        let bv = b.get();
-        let (nr, ng, nb) = math::hsb_to_rgb(hv, sv, bv);
-        if (r.get_untracked() - nr).abs() > 0.002
-            || (g.get_untracked() - ng).abs() > 0.002
-            || (bl.get_untracked() - nb).abs() > 0.002
-        {
-            rgb_guard_fwd.set(true);
-            r.set(nr);
-            g.set(ng);
-            bl.set(nb);
-            rgb_guard_fwd.set(false);
+/// CSS notation produced by the hex row's copy button.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum CopyFormat {
+    Hex,
+    Rgb,
+    Hsl,
+}
+
+impl CopyFormat {
+    fn next(self) -> Self {
+        match self {
+            Self::Hex => Self::Rgb,
+            Self::Rgb => Self::Hsl,
+            Self::Hsl => Self::Hex,
         }
-    });
+    }
 
-    // RGB -> HSB back-sync (when RGB inputs change)
-    let rgb_guard_back = rgb_from_hsb;
-    create_effect(move |_| {
-        let rv = r.get();
-        let gv = g.get();
-        let bv = bl.get();
-        if rgb_guard_back.get() {
-            return;
+    fn label(self) -> &'static str {
+        match self {
+            Self::Hex => "HEX",
+            Self::Rgb => "RGB",
+            Self::Hsl => "HSL",
         }
-        let (new_h, new_s, new_b) = math::rgb_to_hsb(rv, gv, bv);
-        if new_s > 0.001 && new_b > 0.001 && (h.get_untracked() - new_h).abs() > 0.002 {
-            h.set(new_h);
+    }
+
+    fn format(self, c: &SolidColor) -> String {
+        match self {
+            Self::Hex => c.to_css_hex(),
+            Self::Rgb => c.to_css_rgb(),
+            Self::Hsl => c.to_css_hsl(),
         }
-        if (s.get_untracked() - new_s).abs() > 0.002 {
-            s.set(new_s);
+    }
+}
+
+/// Small text button cycling `format` through the [`CopyFormat`]s.
+fn copy_format_toggle(format: RwSignal<CopyFormat>, show: bool) -> impl IntoView {
+    label(move || format.get().label())
+        .style(move |s| {
+            s.font_size(constants::LABEL_FONT)
+                .color(Color::rgb8(120, 120, 120))
+                .padding_horiz(3.0)
+                .border_radius(3.0)
+                .cursor(floem::style::CursorStyle::Pointer)
+                .hover(|s| s.background(Color::rgb8(230, 230, 230)))
+                .apply_if(!show, |s| s.hide())
+        })
+        .on_event_stop(floem::event::EventListener::PointerUp, move |_| {
+            format.update(|f| *f = f.next());
+        })
+}
+
+/// [`copy_button`], hidden when copy buttons are turned off.
+fn row_copy_button(
+    show: bool,
+    clipboard: SharedClipboard,
+    get_text: impl Fn() -> String + 'static,
+) -> impl IntoView {
+    copy_button(clipboard, get_text).style(move |s| s.apply_if(!show, |s| s.hide()))
+}
+
+/// The editor's HSB and alpha signals, created by the caller so a
+/// [`PickerHandle`](crate::PickerHandle) can read and write them.
+#[derive(Clone, Copy)]
+pub(crate) struct EditorSignals {
+    pub(crate) h: RwSignal<f64>,
+    pub(crate) s: RwSignal<f64>,
+    pub(crate) b: RwSignal<f64>,
+    pub(crate) a: RwSignal<f64>,
+}
+
+impl EditorSignals {
+    pub(crate) fn new() -> Self {
+        Self {
+            h: RwSignal::new(0.0),
+            s: RwSignal::new(0.0),
+            b: RwSignal::new(1.0),
+            a: RwSignal::new(1.0),
         }
-        if (b.get_untracked() - new_b).abs() > 0.002 {
-            b.set(new_b);
+    }
+}
+
+/// Creates a consolidated color editor with HSB, HSL, and RGB input rows.
+///
+/// `validity` tracks whether every text field holds parseable content.
+pub(crate) fn color_editor(
+    color: RwSignal<SolidColor>,
+    config: &PickerConfig,
+    validity: Validity,
+    signals: EditorSignals,
+) -> impl IntoView + use<> {
+    // HSB signals (ground-truth)
+    let EditorSignals { h, s, b, a } = signals;
+    let hex = RwSignal::new("808080FF".to_string());
+
+    // HSL derived signals
+    let s_hsl = RwSignal::new(0.0_f64);
+    let l = RwSignal::new(0.5_f64);
+
+    // RGB derived signals
+    let r = RwSignal::new(0.5_f64);
+    let g = RwSignal::new(0.5_f64);
+    let bl = RwSignal::new(0.5_f64);
+
+    // Opaque pickers pin alpha to 1.0, whatever sets the color
+    let opaque = config.opaque;
+    if opaque {
+        pin_opaque(color);
+    }
+
+    // Initialize from current color
+    {
+        let c = color.get_untracked();
+        let (ch, cs, cb) = c.to_hsb();
+        let (_, sh, lv) = c.to_hsl();
+        h.set(ch);
+        s.set(cs);
+        b.set(cb);
+        a.set(c.a());
+        hex.set(c.to_hex());
+        s_hsl.set(sh);
+        l.set(lv);
+        r.set(c.r());
+        g.set(c.g());
+        bl.set(c.b());
+    }
+
+    // Color and hex <-> the ground-truth model's signals
+    let truth = config.ground_truth;
+    let truth_signals = match truth {
+        GroundTruth::Hsb => [h, s, b],
+        GroundTruth::Hsl => [h, s_hsl, l],
+    };
+    link_ground_truth(color, hex, a, truth, truth_signals);
+
+    // HSB <-> HSL, with whichever model is the ground truth as the source
+    match truth {
+        GroundTruth::Hsb => sync_derived([h, s, b], [s_hsl, l], SYNC_EPS, hsl_of, hsb_of),
+        GroundTruth::Hsl => sync_derived([h, s_hsl, l], [s, b], SYNC_EPS, hsb_sb_of, hsl_from_sb),
+    }
+
+    // HSB <-> RGB
+    sync_derived(
+        [h, s, b],
+        [r, g, bl],
+        SYNC_EPS,
+        |[hv, sv, bv]| {
+            let (nr, ng, nb) = math::hsb_to_rgb(hv, sv, bv);
+            [nr, ng, nb]
+        },
+        |[rv, gv, bv], [hv, _, _]| {
+            let (new_h, new_s, new_b) = math::rgb_to_hsb(rv, gv, bv);
+            // Keep the previous hue for grays, where RGB carries none
+            let gray = SolidColor::from_rgba(rv, gv, bv, 1.0).is_approximately_gray(GRAY_EPS);
+            let new_h = if gray { hv } else { new_h };
+            [new_h, new_s, new_b]
+        },
+    );
+
+    // sRGB <-> linear RGB (shown in the RGB row in linear mode)
+    let linear = [
+        RwSignal::new(0.0_f64),
+        RwSignal::new(0.0),
+        RwSignal::new(0.0),
+    ];
+    if config.rgb_display == RgbDisplay::Linear {
+        sync_derived([r, g, bl], linear, LINEAR_SYNC_EPS, linear_of, srgb_of);
+    }
+
+    // RGB <-> naive CMYK (feature-gated row)
+    #[cfg(feature = "cmyk")]
+    let cmyk = [
+        RwSignal::new(0.0_f64),
+        RwSignal::new(0.0),
+        RwSignal::new(0.0),
+        RwSignal::new(1.0),
+    ];
+    #[cfg(feature = "cmyk")]
+    sync_derived(
+        [r, g, bl],
+        cmyk,
+        SYNC_EPS,
+        |[rv, gv, bv]| {
+            let (c, m, y, k) = math::rgb_to_cmyk(rv, gv, bv);
+            [c, m, y, k]
+        },
+        |[c, m, y, k], _| {
+            let (rv, gv, bv) = math::cmyk_to_rgb(c, m, y, k);
+            [rv, gv, bv]
+        },
+    );
+
+    // RGB <-> OKLCH (feature-gated row). Chroma is stored as a fraction of
+    // OKLCH_CHROMA_FULL; out-of-gamut edits are mapped back into sRGB by
+    // reducing chroma, and the fields then show the mapped color.
+    #[cfg(feature = "oklch")]
+    let oklch = [
+        RwSignal::new(1.0_f64),
+        RwSignal::new(0.0),
+        RwSignal::new(0.0),
+    ];
+    #[cfg(feature = "oklch")]
+    sync_derived(
+        [r, g, bl],
+        oklch,
+        SYNC_EPS,
+        |[rv, gv, bv]| {
+            let (ol, oa, ob) = math::srgb_to_oklab(rv, gv, bv);
+            let (ol, oc, oh) = math::oklab_to_oklch(ol, oa, ob);
+            [ol, (oc / math::OKLCH_CHROMA_FULL).min(1.0), oh]
+        },
+        |[ol, oc, oh], _| {
+            let (rv, gv, bv) = math::oklch_to_srgb_in_gamut(ol, oc * math::OKLCH_CHROMA_FULL, oh);
+            [rv, gv, bv]
+        },
+    );
+
+    // Background for the contrast tools, editable via its swatch
+    let has_bg = config.background.is_some() || config.fix_contrast_against.is_some();
+    let show_apca = config.show_apca;
+    let has_badge = config.contrast_against.is_some();
+    let bg = RwSignal::new(
+        config
+            .background
+            .or(config.fix_contrast_against)
+            .unwrap_or(SolidColor::from_rgb(255, 255, 255)),
+    );
+    let bg_hex = RwSignal::new(bg.get_untracked().to_hex());
+    let bg_open = RwSignal::new(false);
+    create_effect(move |_| {
+        let new_hex = bg.get().to_hex();
+        if bg_hex.get_untracked() != new_hex {
+            bg_hex.set(new_hex);
+        }
+    });
+    create_effect(move |_| {
+        if let Some(c) = SolidColor::from_hex(&bg_hex.get())
+            && c.to_hex() != bg.get_untracked().to_hex()
+        {
+            bg.set(c);
         }
     });
 
-    // Build layout
-    v_stack((
-        // Color wheel (hue + saturation)
-        color_wheel(h, s, b).style(|s| s.margin_top(12.0)),
+    if let Some(history) = config.history {
+        history::record(color, history, config.history_len);
+    }
+
+    // Allowed hues: snap hue edits from inputs and external colors
+    if let Some(allowed) = config.allowed_hues.clone() {
+        create_effect(move |_| {
+            let hv = h.get();
+            let snapped = math::snap_hue(hv, &allowed);
+            if (snapped - hv).abs() > HUE_SNAP_EPS {
+                h.set(snapped);
+            }
+        });
+    }
+
+    let steps = config.steps;
+    let spacing = config.spacing;
+    let (show_hsb, show_hsl, show_rgb) = (
+        config.show_hsb_row,
+        config.show_hsl_row,
+        config.show_rgb_row,
+    );
+    let show_wheel = config.show_wheel;
+    let show_sliders = config.show_sliders;
+    let show_hex_channels = config.show_hex_channels_row;
+    let show_extra_rows = show_hex_channels
+        || (cfg!(feature = "cmyk") && config.show_cmyk_row)
+        || (cfg!(feature = "oklch") && config.show_oklch_row)
+        || (cfg!(feature = "kelvin") && config.show_white_balance_row);
+    #[cfg(feature = "cmyk")]
+    let show_cmyk = config.show_cmyk_row;
+    #[cfg(feature = "oklch")]
+    let show_oklch = config.show_oklch_row;
+    let live_numeric = config.live_numeric;
+    let rgb_display = config.rgb_display;
+    let rgb_row = match rgb_display {
+        RgbDisplay::Srgb => [r, g, bl],
+        RgbDisplay::Linear => linear,
+    };
+    let [hsb_labels, hsl_labels, rgb_labels] = row_labels(config);
+    // Display order only; the signals keep their meaning
+    let rgb_order = config.rgb_channel_order;
+    let rgb_row = rgb_order.indices().map(|i| rgb_row[i]);
+    let rgb_labels = rgb_order.indices().map(|i| rgb_labels[i]);
+    // The pad replaces the brightness and alpha sliders
+    let use_pad = cfg!(feature = "alpha") && config.brightness_alpha_pad && !opaque;
+    let show_brightness = config.show_brightness_slider && show_sliders && !use_pad;
+    #[cfg(feature = "alpha")]
+    let alpha_quantize = config
+        .quantize_output
+        .then_some(match config.alpha_display {
+            crate::config::AlphaDisplay::Percent => 100.0,
+            crate::config::AlphaDisplay::HexByte => 255.0,
+        });
+    let hue_unit = config.hue_unit;
+    let show_copy = config.show_copy_buttons;
+    // CSS notation of the hex row's copy button, kept while the picker lives
+    let copy_format = RwSignal::new(CopyFormat::Hex);
+    let complete_names = config.complete_color_names;
+    let labels = config.labels;
+    let blur = WindowBlur::new(config.window_blur_commit);
+    let scrub_labels = config.scrub_labels;
+    let clipboard = config.clipboard.clone();
+    let named_palette = Rc::new(config.named_palette.clone());
+    let token_name = config.design_token_name.clone();
+    let show_tokens = token_name.is_some();
+    let has_palette = !named_palette.is_empty();
+    let palette_threshold = config.palette_match_threshold;
+    let percent_quantize = config.quantize_output.then_some(100.0);
+
+    // Focused numeric fields veil the controls that don't edit their value
+    let highlight = config.focus_highlight.then(Highlight::new);
+    let field = move |dim| highlight.map(|h| h.field(dim));
+
+    // Signals drawn by the wheel and sliders, eased toward outside changes
+    // when animation is on
+    let animate = config.animate_external_changes && !config.reduced_motion;
+    #[cfg_attr(not(feature = "alpha"), allow(unused_variables))]
+    let [dh, ds, db, da] = if animate {
+        animate::eased_display([h, s, b, a], config.animation_duration)
+    } else {
+        [h, s, b, a]
+    };
+
+    // The wheel edits either HSB or HSL saturation, with the matching value
+    let (wheel_sat, wheel_value) = match config.wheel_mode {
+        WheelMode::Hsb => (ds, db),
+        WheelMode::Hsl if animate => {
+            let [dsh, dl] = [RwSignal::new(0.0_f64), RwSignal::new(0.5_f64)];
+            sync_derived([dh, ds, db], [dsh, dl], SYNC_EPS, hsl_of, hsb_of);
+            (dsh, dl)
+        }
+        WheelMode::Hsl => (s_hsl, l),
+    };
+
+    // Density of the last dropped image, overlaid on the wheel
+    #[cfg(feature = "image")]
+    let heatmap = RwSignal::new(None::<std::rc::Rc<Heatmap>>);
+    #[cfg(feature = "image")]
+    let (show_heatmap, wheel_mode) = (config.show_image_heatmap, config.wheel_mode);
+
+    // Color wheel (hue + saturation)
+    let wheel = color_wheel(
+        dh,
+        wheel_sat,
+        wheel_value,
+        config.allowed_hues.clone(),
+        config.theme,
+        steps,
+        config.wheel_mode,
+        config.full_brightness_wheel,
+        config.quantize_output,
+    );
+    #[cfg(feature = "image")]
+    let wheel = wheel.heatmap(heatmap);
+    let wheel = dim_unless(wheel, highlight, &[Dimension::Hue, Dimension::Saturation])
+        .style(move |s| s.margin_top(12.0).apply_if(!show_wheel, |s| s.hide()));
+
+    // Sliders, swatch and rows
+    let controls = v_stack((
+        // Saturation ramp (optional)
+        dim_unless(
+            saturation_slider(
+                dh,
+                ds,
+                db,
+                config.theme,
+                steps.wheel_saturation,
+                percent_quantize,
+            ),
+            highlight,
+            &[Dimension::Saturation],
+        )
+        .style({
+            let show = config.show_saturation_slider && show_sliders;
+            move |s| s.margin_horiz(8.0).apply_if(!show, |s| s.hide())
+        }),
         // Eyedropper + color swatch row
         h_stack((
-            #[cfg(all(feature = "eyedropper", target_os = "macos"))]
-            eyedropper_button(color),
+            #[cfg(all(
+                feature = "eyedropper",
+                any(target_os = "macos", target_os = "linux", target_os = "windows")
+            ))]
+            eyedropper_button(color, config.on_out_of_gamut_sample.clone()),
+            // Swap to the complementary hue; saturation and brightness are kept
+            icon_button(lucide_icons::Icon::ArrowLeftRight, move || {
+                h.set(palette::complementary_hue(h.get_untracked()));
+            })
+            .style(|s| s.align_self(Some(floem::taffy::AlignItems::Center))),
+            // Snap to the nearest web-safe color
+            {
+                let show = config.show_web_safe_snap;
+                icon_button(lucide_icons::Icon::Magnet, move || {
+                    color.set(palette::nearest_web_safe(&color.get_untracked()));
+                })
+                .style(move |s| {
+                    s.align_self(Some(floem::taffy::AlignItems::Center))
+                        .apply_if(!show, |s| s.hide())
+                })
+            },
+            // Snap to the nearest named palette entry
+            {
+                let named = Rc::clone(&named_palette);
+                icon_button(lucide_icons::Icon::Palette, move || {
+                    if let Some((_, c)) = palette::nearest_in(&named, &color.get_untracked()) {
+                        color.set(SolidColor::from_rgba(
+                            c.r(),
+                            c.g(),
+                            c.b(),
+                            color.get_untracked().a(),
+                        ));
+                    }
+                })
+                .style(move |s| {
+                    s.align_self(Some(floem::taffy::AlignItems::Center))
+                        .apply_if(!has_palette, |s| s.hide())
+                })
+            },
+            // Fix contrast against the background
+            icon_button(lucide_icons::Icon::Contrast, move || {
+                color.set(
+                    color
+                        .get_untracked()
+                        .adjust_for_contrast(&bg.get_untracked(), constants::CONTRAST_AA),
+                );
+            })
+            .style(move |s| {
+                s.align_self(Some(floem::taffy::AlignItems::Center))
+                    .apply_if(!has_bg, |s| s.hide())
+            }),
             // Spacer pushes swatch to the right
             empty().style(|s| s.flex_grow(1.0)),
+            // Name of the matching palette entry, if any
             {
-                let color_copy = color;
-                empty().style(move |st| {
-                    let c = color_copy.get();
-                    st.width(32.0)
-                        .height(32.0)
+                let named = Rc::clone(&named_palette);
+                label(move || {
+                    palette::name_in(&named, &color.get(), palette_threshold)
+                        .unwrap_or_default()
+                        .to_string()
+                })
+                .style(move |s| {
+                    s.font_size(constants::LABEL_FONT + 1.0)
+                        .color(Color::rgb8(84, 84, 84))
+                        .margin_right(6.0)
+                        .apply_if(!has_palette, |s| s.hide())
+                })
+            },
+            // Warm/cool glyph
+            {
+                let show = config.show_temperature;
+                label(move || {
+                    let bias = color.get().temperature_bias();
+                    if bias > TEMPERATURE_GLYPH_BIAS {
+                        icon_font::text(lucide_icons::Icon::Sun)
+                    } else if bias < -TEMPERATURE_GLYPH_BIAS {
+                        icon_font::text(lucide_icons::Icon::Snowflake)
+                    } else {
+                        String::new()
+                    }
+                })
+                .style(move |s| {
+                    icon_font::font(s)
+                        .font_size(14.0)
+                        .color(Color::rgb8(120, 120, 120))
+                        .margin_right(6.0)
+                        .apply_if(!show, |s| s.hide())
+                })
+            },
+            // Contrast readout and background swatch
+            label(move || contrast_readout(&color.get(), &bg.get(), show_apca)).style(move |s| {
+                s.font_size(constants::LABEL_FONT + 1.0)
+                    .font_family("monospace".to_string())
+                    .color(Color::rgb8(84, 84, 84))
+                    .margin_right(4.0)
+                    .apply_if(!has_bg, |s| s.hide())
+            }),
+            empty()
+                .style(move |s| {
+                    let c = bg.get();
+                    s.size(20.0, 20.0)
+                        .margin_right(6.0)
                         .border_radius(constants::RADIUS)
                         .border(1.0)
                         .border_color(Color::rgb8(180, 180, 180))
-                        .background(Color::rgba(c.r(), c.g(), c.b(), c.a()))
+                        .background(Color::from(c))
+                        .cursor(floem::style::CursorStyle::Pointer)
+                        .apply_if(!has_bg, |s| s.hide())
                 })
-            },
+                .on_click_stop(move |_| bg_open.update(|open| *open = !*open)),
+            swatch(color, s, b, config.scrub_swatch, config.compare_swatch),
+        ))
+        .style(move |st| {
+            st.items_center()
+                .margin_horiz(8.0)
+                .apply_if(!show_wheel, |s| s.margin_top(8.0))
+        }),
+        // Contrast tools: background popover (toggled by clicking the
+        // background swatch) and the WCAG badge row
+        v_stack((
+            background_popover(
+                bg,
+                bg_hex,
+                bg_open,
+                config.theme,
+                steps,
+                validity,
+                blur,
+                complete_names,
+                labels,
+            ),
+            contrast_badge(color, config.contrast_against),
         ))
-        .style(|st| st.items_center().margin_horiz(8.0)),
+        .style(move |s| {
+            s.gap(spacing.gap)
+                .apply_if(!has_badge && !bg_open.get(), |s| s.hide())
+        }),
+        // macOS system swatches (feature-gated)
+        #[cfg(all(feature = "system-colors", target_os = "macos"))]
+        system_colors_strip(color, config.show_system_colors),
+        // Recently used colors (when a history signal is configured)
+        history_strip(color, config.history),
+        // R/G/B share of the color (optional)
+        channel_bar(color).style({
+            let show = config.show_channel_bar;
+            move |s| s.margin_horiz(8.0).apply_if(!show, |s| s.hide())
+        }),
         // Brightness slider
-        brightness_slider(h, s, b).style(|s| s.margin_horiz(8.0)),
+        dim_unless(
+            brightness_slider(
+                dh,
+                ds,
+                db,
+                config.slider_ticks.clone(),
+                config.theme,
+                steps.slider,
+                percent_quantize,
+                config.slider_response,
+            ),
+            highlight,
+            &[Dimension::Brightness, Dimension::Lightness],
+        )
+        .style(move |s| s.margin_horiz(8.0).apply_if(!show_brightness, |s| s.hide())),
+        // Brightness (across) + alpha (down) pad (feature-gated)
+        #[cfg(feature = "alpha")]
+        dim_unless(
+            brightness_alpha_pad(
+                dh,
+                ds,
+                db,
+                da,
+                config.theme,
+                steps.slider,
+                (percent_quantize, alpha_quantize),
+                config.alpha_snap,
+            ),
+            highlight,
+            &[
+                Dimension::Brightness,
+                Dimension::Lightness,
+                Dimension::Alpha,
+            ],
+        )
+        .style(move |s| {
+            s.margin_horiz(8.0)
+                .apply_if(!(use_pad && show_sliders), |s| s.hide())
+        }),
         // Alpha slider + percentage (feature-gated)
         #[cfg(feature = "alpha")]
         h_stack((
-            alpha_slider(a, move || {
-                let (r, g, bl) = math::hsb_to_rgb(h.get(), s.get(), b.get());
-                (r, g, bl)
-            })
-            .style(|s| s.flex_grow(1.0)),
-            alpha_input(a),
+            dim_unless(
+                alpha_slider(
+                    da,
+                    move || {
+                        let (r, g, bl) = math::hsb_to_rgb(dh.get(), ds.get(), db.get());
+                        (r, g, bl)
+                    },
+                    config.alpha_thumb_preview,
+                    config.slider_ticks.clone(),
+                    config.theme,
+                    steps.slider,
+                    alpha_quantize,
+                    config.alpha_snap,
+                    config.slider_response,
+                ),
+                highlight,
+                &[Dimension::Alpha],
+            )
+            .style(move |s| {
+                s.flex_grow(1.0)
+                    .apply_if(!show_sliders || use_pad, |s| s.hide())
+            }),
+            alpha_input(
+                a,
+                steps.numeric,
+                config.alpha_display,
+                config.alpha_snap,
+                validity,
+                blur,
+                field(Dimension::Alpha),
+                labels.percent,
+            ),
         ))
-        .style(|s| s.margin_horiz(8.0).gap(4.0)),
+        .style(move |s| s.margin_horiz(8.0).gap(4.0).apply_if(opaque, |s| s.hide())),
         // Hex + copy row
-        h_stack((hex_input(hex), copy_button(move || hex.get().to_string())))
-            .style(|st| st.gap(constants::GAP).items_center().justify_center()),
+        h_stack((
+            hex_input(
+                hex,
+                steps.slider,
+                validity,
+                blur,
+                complete_names,
+                labels.hex,
+            ),
+            copy_format_toggle(copy_format, show_copy),
+            row_copy_button(show_copy, clipboard.clone(), move || {
+                copy_format.get().format(&color.get())
+            }),
+            #[cfg(feature = "image")]
+            icon_button(lucide_icons::Icon::Image, move || {
+                let _ = crate::swatch_image::copy_swatch_image(&color.get_untracked());
+            })
+            .style(move |s| s.apply_if(!show_copy, |s| s.hide())),
+            copy_button_with_icon(lucide_icons::Icon::Braces, clipboard.clone(), move || {
+                let ramp: Vec<_> = palette::MATERIAL_TONES
+                    .into_iter()
+                    .zip(palette::material_tones(color.get()))
+                    .collect();
+                palette::to_design_tokens(token_name.as_deref().unwrap_or_default(), &ramp)
+            })
+            .style(move |s| s.apply_if(!show_tokens, |s| s.hide())),
+        ))
+        .style(move |st| st.gap(spacing.gap).items_center().justify_center()),
         // HSB inputs row
         h_stack((
-            number_input("H", h, 360.0),
-            number_input("S", s, 100.0),
-            number_input("B", b, 100.0),
-            copy_button(move || {
-                format!(
-                    "{}, {}, {}",
-                    (h.get() * 360.0).round() as i64,
-                    (s.get() * 100.0).round() as i64,
-                    (b.get() * 100.0).round() as i64,
-                )
+            number_input(
+                hsb_labels[0],
+                h,
+                360.0,
+                steps.numeric,
+                validity,
+                blur,
+                live_numeric,
+                scrub_labels,
+                field(Dimension::Hue),
+            ),
+            number_input(
+                hsb_labels[1],
+                s,
+                100.0,
+                steps.numeric,
+                validity,
+                blur,
+                live_numeric,
+                scrub_labels,
+                field(Dimension::Saturation),
+            ),
+            number_input(
+                hsb_labels[2],
+                b,
+                100.0,
+                steps.numeric,
+                validity,
+                blur,
+                live_numeric,
+                scrub_labels,
+                field(Dimension::Brightness),
+            ),
+            row_copy_button(show_copy, clipboard.clone(), move || {
+                color::hue_function("hsb", &hue_unit.css(h.get()), s.get(), b.get(), None)
             }),
         ))
-        .style(|st| st.gap(constants::GAP / 2.0).items_center().justify_center()),
+        .style(move |st| {
+            st.gap(spacing.row_gap)
+                .items_center()
+                .justify_center()
+                .apply_if(!show_hsb, |s| s.hide())
+        }),
         // HSL inputs row
         h_stack((
-            number_input("H", h, 360.0),
-            number_input("S", s_hsl, 100.0),
-            number_input("L", l, 100.0),
-            copy_button(move || {
-                format!(
-                    "{}, {}, {}",
-                    (h.get() * 360.0).round() as i64,
-                    (s_hsl.get() * 100.0).round() as i64,
-                    (l.get() * 100.0).round() as i64,
-                )
+            number_input(
+                hsl_labels[0],
+                h,
+                360.0,
+                steps.numeric,
+                validity,
+                blur,
+                live_numeric,
+                scrub_labels,
+                field(Dimension::Hue),
+            ),
+            number_input(
+                hsl_labels[1],
+                s_hsl,
+                100.0,
+                steps.numeric,
+                validity,
+                blur,
+                live_numeric,
+                scrub_labels,
+                field(Dimension::Saturation),
+            ),
+            number_input(
+                hsl_labels[2],
+                l,
+                100.0,
+                steps.numeric,
+                validity,
+                blur,
+                live_numeric,
+                scrub_labels,
+                field(Dimension::Lightness),
+            ),
+            row_copy_button(show_copy, clipboard.clone(), move || {
+                color::hue_function("hsl", &hue_unit.css(h.get()), s_hsl.get(), l.get(), None)
             }),
         ))
-        .style(|st| st.gap(constants::GAP / 2.0).items_center().justify_center()),
+        .style(move |st| {
+            st.gap(spacing.row_gap)
+                .items_center()
+                .justify_center()
+                .apply_if(!show_hsl, |s| s.hide())
+        }),
         // RGB inputs row
         h_stack((
-            number_input("sR", r, 255.0),
-            number_input("G", g, 255.0),
-            number_input("B", bl, 255.0),
-            copy_button(move || {
-                format!(
-                    "{}, {}, {}",
-                    (r.get() * 255.0).round() as i64,
-                    (g.get() * 255.0).round() as i64,
-                    (bl.get() * 255.0).round() as i64,
+            number_input(
+                rgb_labels[0],
+                rgb_row[0],
+                255.0,
+                steps.numeric,
+                validity,
+                blur,
+                live_numeric,
+                scrub_labels,
+                None,
+            ),
+            number_input(
+                rgb_labels[1],
+                rgb_row[1],
+                255.0,
+                steps.numeric,
+                validity,
+                blur,
+                live_numeric,
+                scrub_labels,
+                None,
+            ),
+            number_input(
+                rgb_labels[2],
+                rgb_row[2],
+                255.0,
+                steps.numeric,
+                validity,
+                blur,
+                live_numeric,
+                scrub_labels,
+                None,
+            ),
+            row_copy_button(show_copy, clipboard.clone(), move || {
+                rgb_copy_text(&color.get(), rgb_order, rgb_display)
+            }),
+        ))
+        .style(move |st| {
+            st.gap(spacing.row_gap)
+                .items_center()
+                .justify_center()
+                .apply_if(!show_rgb, |s| s.hide())
+        }),
+        // Optional model rows (feature-gated) and the per-channel hex row
+        v_stack((
+            // CMYK inputs row (feature-gated)
+            #[cfg(feature = "cmyk")]
+            h_stack((
+                number_input(
+                    labels.cmyk[0],
+                    cmyk[0],
+                    100.0,
+                    steps.numeric,
+                    validity,
+                    blur,
+                    live_numeric,
+                    scrub_labels,
+                    None,
+                ),
+                number_input(
+                    labels.cmyk[1],
+                    cmyk[1],
+                    100.0,
+                    steps.numeric,
+                    validity,
+                    blur,
+                    live_numeric,
+                    scrub_labels,
+                    None,
+                ),
+                number_input(
+                    labels.cmyk[2],
+                    cmyk[2],
+                    100.0,
+                    steps.numeric,
+                    validity,
+                    blur,
+                    live_numeric,
+                    scrub_labels,
+                    None,
+                ),
+                number_input(
+                    labels.cmyk[3],
+                    cmyk[3],
+                    100.0,
+                    steps.numeric,
+                    validity,
+                    blur,
+                    live_numeric,
+                    scrub_labels,
+                    None,
+                ),
+                row_copy_button(show_copy, clipboard.clone(), move || {
+                    cmyk_copy_text(cmyk.map(|v| v.get()))
+                }),
+            ))
+            .style(move |st| {
+                st.gap(spacing.row_gap)
+                    .items_center()
+                    .justify_center()
+                    .apply_if(!show_cmyk, |s| s.hide())
+            }),
+            // OKLCH inputs row (feature-gated)
+            #[cfg(feature = "oklch")]
+            h_stack((
+                number_input(
+                    labels.oklch[0],
+                    oklch[0],
+                    100.0,
+                    steps.numeric,
+                    validity,
+                    blur,
+                    live_numeric,
+                    scrub_labels,
+                    None,
+                ),
+                number_input(
+                    labels.oklch[1],
+                    oklch[1],
+                    100.0,
+                    steps.numeric,
+                    validity,
+                    blur,
+                    live_numeric,
+                    scrub_labels,
+                    None,
+                ),
+                number_input(
+                    labels.oklch[2],
+                    oklch[2],
+                    360.0,
+                    steps.numeric,
+                    validity,
+                    blur,
+                    live_numeric,
+                    scrub_labels,
+                    None,
+                ),
+                row_copy_button(show_copy, clipboard.clone(), move || {
+                    color.get().to_oklch_string()
+                }),
+            ))
+            .style(move |st| {
+                st.gap(spacing.row_gap)
+                    .items_center()
+                    .justify_center()
+                    .apply_if(!show_oklch, |s| s.hide())
+            }),
+            // White-balance sliders (feature-gated)
+            #[cfg(feature = "kelvin")]
+            white_balance_row(color, labels.white_balance, config.show_white_balance_row),
+            // Per-channel hex row
+            h_stack((
+                hex_byte_input(
+                    labels.hex_channels[0],
+                    r,
+                    steps.numeric,
+                    validity,
+                    blur,
+                    live_numeric,
+                ),
+                hex_byte_input(
+                    labels.hex_channels[1],
+                    g,
+                    steps.numeric,
+                    validity,
+                    blur,
+                    live_numeric,
+                ),
+                hex_byte_input(
+                    labels.hex_channels[2],
+                    bl,
+                    steps.numeric,
+                    validity,
+                    blur,
+                    live_numeric,
+                ),
+                #[cfg(feature = "alpha")]
+                hex_byte_input(
+                    labels.hex_channels[3],
+                    a,
+                    steps.numeric,
+                    validity,
+                    blur,
+                    live_numeric,
                 )
+                .style(move |s| s.apply_if(opaque, |s| s.hide())),
+            ))
+            .style(move |st| {
+                st.gap(spacing.row_gap)
+                    .items_center()
+                    .justify_center()
+                    .apply_if(!show_hex_channels, |s| s.hide())
             }),
         ))
-        .style(|st| st.gap(constants::GAP / 2.0).items_center().justify_center()),
+        .style(move |st| st.gap(spacing.gap).apply_if(!show_extra_rows, |s| s.hide())),
     ))
-    .style(|st| {
-        st.gap(constants::GAP)
-            .padding_horiz(constants::PADDING)
-            .padding_bottom(constants::PADDING)
+    .style(move |st| st.gap(spacing.gap));
+
+    // Build layout
+    let body = match config.layout {
+        EditorLayout::Vertical => v_stack((wheel, controls)).into_any(),
+        EditorLayout::WheelLeft => h_stack((
+            wheel.style(|s| s.flex_basis(0.0).min_width(120.0)),
+            controls.style(|s| {
+                s.flex_grow(1.0)
+                    .flex_basis(0.0)
+                    .min_width(240.0)
+                    .margin_top(12.0)
+            }),
+        ))
+        .into_any(),
+    };
+    body.style(move |st| {
+        st.gap(spacing.gap)
+            .padding_horiz(spacing.padding)
+            .padding_bottom(spacing.padding)
             .padding_top(2.0)
             .size_full()
             .justify_center()
-            .background(Color::rgb8(242, 242, 242))
+            .background(Color::rgb8(
+                constants::PANEL_GRAY,
+                constants::PANEL_GRAY,
+                constants::PANEL_GRAY,
+            ))
+    })
+    // Let fields tell a window switch from a move to another field
+    .on_event_cont(floem::event::EventListener::WindowGotFocus, move |_| {
+        blur.set_active(true);
+    })
+    .on_event_cont(floem::event::EventListener::WindowLostFocus, move |_| {
+        blur.set_active(false);
+    })
+    // Dropped hex/name text files and images set the color
+    .on_event_stop(floem::event::EventListener::DroppedFile, move |e| {
+        let floem::event::Event::DroppedFile(drop) = e else {
+            return;
+        };
+        // Images (with the `image` feature) are sampled at their center
+        // pixel and decoded once for both the color and the heatmap
+        #[cfg(feature = "image")]
+        if let Ok(img) = image::open(&drop.path) {
+            let img = img.to_rgba8();
+            if show_heatmap {
+                let map = Heatmap::from_image(&img, wheel_mode);
+                heatmap.set(Some(std::rc::Rc::new(map)));
+            }
+            if let Some(c) = image_center_color(&img) {
+                color.set(c);
+            }
+            return;
+        }
+        if let Some(c) = color_from_text_file(&drop.path) {
+            color.set(c);
+        }
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_near(sig: RwSignal<f64>, want: f64) {
+        let got = sig.get_untracked();
+        assert!((got - want).abs() < 1e-9, "{got} != {want}");
+    }
+
+    /// HSL ground truth as the editor wires it: `[h, s_hsl, l]` drive `[s, b]`.
+    fn hsl_ground_truth(h: f64, sh: f64, lv: f64) -> ([RwSignal<f64>; 3], [RwSignal<f64>; 2]) {
+        let hsl = [RwSignal::new(h), RwSignal::new(sh), RwSignal::new(lv)];
+        let sb = [RwSignal::new(0.0), RwSignal::new(0.0)];
+        sync_derived(hsl, sb, SYNC_EPS, hsb_sb_of, hsl_from_sb);
+        (hsl, sb)
+    }
+
+    #[test]
+    fn hsl_ground_truth_keeps_saturation_across_lightness_edits() {
+        let ([_, s_hsl, l], _) = hsl_ground_truth(0.6, 0.8, 0.5);
+        for lv in [0.3, 0.0, 0.9, 1.0, 0.5] {
+            l.set(lv);
+            assert_eq!(s_hsl.get_untracked(), 0.8);
+        }
+        assert_eq!(l.get_untracked(), 0.5);
+    }
+
+    #[test]
+    fn hsl_ground_truth_keeps_saturation_through_black() {
+        let ([h, s_hsl, l], [s, b]) = hsl_ground_truth(0.6, 0.8, 0.5);
+        let [s0, b0] = [s.get_untracked(), b.get_untracked()];
+        // Brightness slider dragged to black: HSL saturation is undefined
+        // there, so the stored value survives
+        b.set(0.0);
+        assert_near(l, 0.0);
+        assert_eq!(s_hsl.get_untracked(), 0.8);
+        assert_eq!(h.get_untracked(), 0.6);
+        // Raising lightness again restores the original color
+        l.set(0.5);
+        assert_near(s, s0);
+        assert_near(b, b0);
+    }
+
+    #[test]
+    fn rgb_labels_follow_the_display_mode() {
+        assert_eq!(default_rgb_labels(RgbDisplay::Srgb), ["sR", "G", "B"]);
+        assert_eq!(default_rgb_labels(RgbDisplay::Linear), ["R", "G", "B"]);
+    }
+
+    #[test]
+    fn linear_rgb_row_shows_to_linear_rgb() {
+        let c = SolidColor::from_rgb(0x3B, 0x82, 0xF6);
+        let srgb = [
+            RwSignal::new(c.r()),
+            RwSignal::new(c.g()),
+            RwSignal::new(c.b()),
+        ];
+        let linear = [RwSignal::new(0.0), RwSignal::new(0.0), RwSignal::new(0.0)];
+        sync_derived(srgb, linear, LINEAR_SYNC_EPS, linear_of, srgb_of);
+
+        let (lr, lg, lb) = c.to_linear_rgb();
+        for (sig, want) in linear.iter().zip([lr, lg, lb]) {
+            assert_near(*sig, want);
+            // The field shows the 0–255 value of the linear channel
+            assert_eq!(
+                (sig.get_untracked() * 255.0).round(),
+                (want * 255.0).round()
+            );
+        }
+
+        // Editing a linear field writes the encoded channel back
+        linear[0].set(0.5);
+        assert_near(srgb[0], math::linear_to_srgb(0.5));
+    }
+
+    #[test]
+    fn tolerances_sit_between_rounding_and_one_step() {
+        let step = 1.0 / 255.0;
+        let rounding = step / 2.0;
+        assert!(HEX_EPS > rounding && HEX_EPS < step);
+        assert!(HSB_MATCH_EPS > rounding);
+        for eps in [COLOR_EPS, SYNC_EPS, GRAY_EPS] {
+            assert!(eps < step);
+        }
+        // The darkest linear step: 1/255 encoded is ≈ 0.0003 linear
+        assert!(LINEAR_SYNC_EPS < math::srgb_to_linear(2.0 / 255.0));
+    }
+
+    #[test]
+    fn hsb_round_trip_stays_within_color_eps() {
+        for r in (0..=255).step_by(17) {
+            for g in (0..=255).step_by(17) {
+                for b in (0..=255).step_by(17) {
+                    let c = SolidColor::from_rgb(r, g, b);
+                    let (h, s, v) = c.to_hsb();
+                    let back = SolidColor::from_hsb(h, s, v, 1.0);
+                    for (x, y) in [(c.r(), back.r()), (c.g(), back.g()), (c.b(), back.b())] {
+                        assert!((x - y).abs() < COLOR_EPS, "{}", c.to_hex());
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn hsl_row_converges_after_an_edit() {
+        let hsb = [RwSignal::new(0.6), RwSignal::new(0.76), RwSignal::new(0.96)];
+        let hsl = [RwSignal::new(0.0), RwSignal::new(0.0)];
+        sync_derived(hsb, hsl, SYNC_EPS, hsl_of, hsb_of);
+        hsl[1].set(0.3);
+        // The written value survives the trip through HSB unchanged
+        assert!((hsl[1].get_untracked() - 0.3).abs() < SYNC_EPS);
+        let [sh, l] = hsl_of(hsb.map(|s| s.get_untracked()));
+        assert!((l - 0.3).abs() < SYNC_EPS);
+        assert!((sh - hsl[0].get_untracked()).abs() < SYNC_EPS);
+    }
+
+    #[test]
+    fn contrast_readout_uses_the_background() {
+        let fg = SolidColor::from_rgb(0, 0, 0);
+        let white = SolidColor::from_rgb(255, 255, 255);
+        assert_eq!(contrast_readout(&fg, &white, false), "21.0:1");
+        assert_eq!(contrast_readout(&fg, &fg, false), "1.0:1");
+        assert!(contrast_readout(&fg, &white, true).starts_with("21.0:1 Lc "));
+    }
+
+    #[test]
+    fn background_popover_edits_reach_the_background() {
+        let bg = RwSignal::new(SolidColor::from_rgb(255, 255, 255));
+        let (h, s, b) = bg.get_untracked().to_hsb();
+        let hsb = [RwSignal::new(h), RwSignal::new(s), RwSignal::new(b)];
+        link_hsb(bg, hsb);
+
+        // Wheel and slider edits
+        batch(|| {
+            hsb[0].set(217.0 / 360.0);
+            hsb[1].set(0.76);
+            hsb[2].set(0.96);
+        });
+        assert_eq!(bg.get_untracked().to_hex(), "3B82F5");
+        let fg = SolidColor::from_rgb(255, 255, 255);
+        assert!(contrast_ratio(&fg, &bg.get_untracked()) < 4.0);
+
+        // Hex edits move the wheel; grays keep the hue
+        bg.set(SolidColor::from_rgb(0x80, 0x80, 0x80));
+        assert_near(hsb[0], 217.0 / 360.0);
+        assert_near(hsb[1], 0.0);
+    }
+
+    #[test]
+    fn bgr_copy_uses_color_bytes_in_bgr_order() {
+        let c = SolidColor::from_hex("#3B82F6").unwrap();
+        assert_eq!(
+            rgb_copy_text(&c, ChannelOrder::Bgr, RgbDisplay::Srgb),
+            "246, 130, 59"
+        );
+        assert_eq!(
+            rgb_copy_text(&c, ChannelOrder::Bgr, RgbDisplay::Linear),
+            "235, 57, 11"
+        );
+        assert_eq!(
+            rgb_copy_text(&c, ChannelOrder::Rgb, RgbDisplay::Srgb),
+            "rgb(59, 130, 246)"
+        );
+    }
+
+    #[test]
+    fn contrast_readout_composites_translucent_colors() {
+        let faint = SolidColor::from_rgba(0.0, 0.0, 0.0, 0.2);
+        let white = SolidColor::from_rgb(255, 255, 255);
+        assert!(contrast_ratio(&faint, &white) < 1.7);
+        assert_eq!(contrast_ratio(&SolidColor::from_rgb(0, 0, 0), &white), 21.0);
+    }
+
+    #[cfg(feature = "kelvin")]
+    #[test]
+    fn white_balance_keeps_alpha() {
+        let c = white_balance_color(3000.0, 0.0, 0.4);
+        let want = SolidColor::from_kelvin(3000.0);
+        assert_eq!(c.to_rgb(), want.to_rgb());
+        assert_eq!(c.a(), 0.4);
+    }
+
+    #[test]
+    fn opaque_edits_keep_alpha_at_one() {
+        let color = RwSignal::new(SolidColor::from_rgba(0.2, 0.4, 0.6, 0.5));
+        pin_opaque(color);
+        assert_eq!(color.get_untracked().a(), 1.0);
+
+        for edit in [
+            SolidColor::from_hex("3B82F680").unwrap(),
+            SolidColor::from_rgba(1.0, 0.0, 0.0, 0.0),
+            SolidColor::from_rgb(0x11, 0x18, 0x27),
+        ] {
+            color.set(edit);
+            let c = color.get_untracked();
+            assert_eq!(c.a(), 1.0);
+            assert_eq!(c.to_hex().len(), 6);
+            assert_eq!(c.to_rgb(), edit.to_rgb());
+        }
+    }
+
+    #[test]
+    fn rows_use_custom_labels() {
+        let config = PickerConfig {
+            labels: Labels {
+                hue: "T",
+                saturation: "Sät",
+                brightness: "Hell",
+                lightness: "Lum",
+                ..Labels::default()
+            },
+            rgb_labels: Some(["Rot", "Grün", "Blau"]),
+            ..Default::default()
+        };
+        assert_eq!(
+            row_labels(&config),
+            [
+                ["T", "Sät", "Hell"],
+                ["T", "Sät", "Lum"],
+                ["Rot", "Grün", "Blau"]
+            ]
+        );
+    }
+
+    #[test]
+    fn rows_default_to_the_current_letters() {
+        let [hsb, hsl, rgb] = row_labels(&PickerConfig::default());
+        assert_eq!(hsb, ["H", "S", "B"]);
+        assert_eq!(hsl, ["H", "S", "L"]);
+        assert_eq!(rgb, ["sR", "G", "B"]);
+        let linear = PickerConfig {
+            rgb_display: RgbDisplay::Linear,
+            ..Default::default()
+        };
+        assert_eq!(row_labels(&linear)[2], ["R", "G", "B"]);
+    }
+
+    #[cfg(feature = "cmyk")]
+    #[test]
+    fn cmyk_copy_is_whole_percents() {
+        let c = SolidColor::from_hex("3B82F6").unwrap();
+        let (cy, m, y, k) = c.to_cmyk();
+        assert_eq!(cmyk_copy_text([cy, m, y, k]), "76, 47, 0, 4");
+        assert_eq!(cmyk_copy_text([0.0, 0.0, 0.0, 1.0]), "0, 0, 0, 100");
+    }
+
+    /// The editor's HSL ground-truth wiring, including the color and hex
+    /// links, starting from `[h, s_hsl, l]`.
+    fn hsl_editor(
+        h: f64,
+        sh: f64,
+        lv: f64,
+    ) -> (RwSignal<SolidColor>, RwSignal<String>, [RwSignal<f64>; 3]) {
+        let color = RwSignal::new(SolidColor::from_hsl(h, sh, lv, 1.0));
+        let hex = RwSignal::new(color.get_untracked().to_hex());
+        let (hsl, _) = hsl_ground_truth(h, sh, lv);
+        link_ground_truth(color, hex, RwSignal::new(1.0), GroundTruth::Hsl, hsl);
+        (color, hex, hsl)
+    }
+
+    #[test]
+    fn hsl_ground_truth_survives_external_color_updates() {
+        let (color, _, [h, s_hsl, l]) = hsl_editor(0.6, 0.8, 0.5);
+        l.set(0.3);
+        // Echo of the editor's own color leaves the typed values alone
+        color.set(color.get_untracked());
+        assert_eq!(s_hsl.get_untracked(), 0.8);
+        assert_eq!(l.get_untracked(), 0.3);
+        // A new external color is written to HSL directly
+        color.set(SolidColor::from_hsl(0.25, 0.4, 0.7, 1.0));
+        assert_near(h, 0.25);
+        assert_near(s_hsl, 0.4);
+        assert_near(l, 0.7);
+        // Black keeps the stored saturation, as from the brightness slider
+        color.set(SolidColor::from_rgb(0, 0, 0));
+        assert_near(l, 0.0);
+        assert_eq!(h.get_untracked(), 0.25);
+        assert_near(s_hsl, 0.4);
+    }
+
+    #[test]
+    fn hsl_ground_truth_takes_committed_hex_directly() {
+        let (color, hex, [h, s_hsl, l]) = hsl_editor(0.6, 0.8, 0.5);
+        hex.set("3B82F6".to_string());
+        let (wh, ws, wl) = SolidColor::from_rgb(0x3B, 0x82, 0xF6).to_hsl();
+        assert_near(h, wh);
+        assert_near(s_hsl, ws);
+        assert_near(l, wl);
+        assert_eq!(color.get_untracked().to_hex(), "3B82F6");
+    }
+}
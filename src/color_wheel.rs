@@ -1,16 +1,26 @@
-//! Circular HSB color wheel.
+//! Circular HSB/HSL color wheel.
 //!
 //! Renders a color wheel where angle maps to hue and radius maps to
 //! saturation. The wheel is rasterized to an RGBA8 pixel buffer and
 //! raster is scaled to widget size rather than redrawn.
+//!
+//! In HSL mode the raster is drawn at L=0.5 and lightness is applied as a
+//! black (L < 0.5) or white (L > 0.5) overlay, which reproduces HSL exactly.
+//!
+//! HSB and HSL are both defined over the sRGB cube, so every point of the
+//! wheel is in gamut at every brightness; there is no gamut boundary to draw.
 
 use std::f64::consts::TAU;
+#[cfg(feature = "image")]
+use std::rc::Rc;
 use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
 
 use floem::kurbo::{Circle, Point, Rect};
 use floem::peniko::{self, Blob, Color};
 
-use floem::reactive::{RwSignal, SignalGet, SignalUpdate, create_effect};
+use floem::ext_event::create_ext_action;
+use floem::reactive::{RwSignal, Scope, SignalGet, SignalUpdate, create_effect};
 use floem::views::Decorators;
 use floem::{
     View, ViewId,
@@ -20,19 +30,45 @@ use floem::{
 
 use floem_renderer::Renderer;
 
+use crate::config::{Steps, WheelMode};
 use crate::constants;
+#[cfg(feature = "image")]
+use crate::heatmap::Heatmap;
 use crate::math;
+use crate::theme::Theme;
 
 /// Feather width in raster pixels for anti-aliasing the circle edge.
 const FEATHER: f64 = 3.0;
 
-/// Rasterize the color wheel at full brightness (V=1.0) to an RGBA8 buffer.
+/// Cursor movement per pointer movement while Alt (precise mode) is held.
+const PRECISE_GAIN: f64 = 0.25;
+
+/// Precise-mode cursor position: the cursor moves from `cursor_anchor` by
+/// the pointer's travel from `pointer_anchor`, scaled by `gain`.
+fn scaled_position(
+    cursor_anchor: Point,
+    pointer_anchor: Point,
+    pointer: Point,
+    gain: f64,
+) -> Point {
+    cursor_anchor + (pointer - pointer_anchor) * gain
+}
+
+/// Rasterize the color wheel at full brightness (V=1.0, or L=0.5 in HSL
+/// mode) to an RGBA8 buffer.
 ///
 /// `width`/`height` are in physical pixels. The circle is inset by
 /// [`FEATHER`] so the full anti-alias gradient fits inside the buffer.
 /// Saturation reaches 1.0 at the circle edge; the feather zone only
 /// affects alpha, not color, so edge pixels stay fully saturated.
-fn rasterize_wheel_base(width: u32, height: u32) -> Vec<u8> {
+///
+/// Checks `cancel` once per row and returns `None` if it was set.
+fn rasterize_wheel_base(
+    width: u32,
+    height: u32,
+    mode: WheelMode,
+    cancel: &AtomicBool,
+) -> Option<Vec<u8>> {
     let cx = width as f64 / 2.0;
     let cy = height as f64 / 2.0;
     let radius = cx.min(cy) - FEATHER;
@@ -40,6 +76,9 @@ fn rasterize_wheel_base(width: u32, height: u32) -> Vec<u8> {
     let mut buf = vec![0u8; (width * height * 4) as usize];
 
     for py in 0..height {
+        if cancel.load(Ordering::Relaxed) {
+            return None;
+        }
         let dy = py as f64 + 0.5 - cy;
         let row_offset = (py * width * 4) as usize;
 
@@ -63,7 +102,7 @@ fn rasterize_wheel_base(width: u32, height: u32) -> Vec<u8> {
                 hue += 1.0;
             }
 
-            let (r, g, b) = math::hsb_to_rgb(hue, sat, 1.0);
+            let (r, g, b) = wheel_color(mode, hue, sat, full_value(mode));
             let offset = row_offset + (px * 4) as usize;
             buf[offset] = (r * 255.0 + 0.5) as u8;
             buf[offset + 1] = (g * 255.0 + 0.5) as u8;
@@ -72,36 +111,106 @@ fn rasterize_wheel_base(width: u32, height: u32) -> Vec<u8> {
         }
     }
 
-    buf
+    Some(buf)
+}
+
+/// The value (brightness or lightness) the base raster is drawn at.
+fn full_value(mode: WheelMode) -> f64 {
+    match mode {
+        WheelMode::Hsb => 1.0,
+        WheelMode::Hsl => 0.5,
+    }
+}
+
+/// Fill drawn over the full-brightness wheel for `brightness`: translucent
+/// black to darken (HSL: black below L=0.5, white above). `None` when
+/// nothing needs drawing, including always with `full_brightness`.
+fn overlay_fill(mode: WheelMode, brightness: f64, full_brightness: bool) -> Option<Color> {
+    let overlay = match mode {
+        WheelMode::Hsb => Color::rgba(0.0, 0.0, 0.0, 1.0 - brightness),
+        WheelMode::Hsl if brightness < 0.5 => Color::rgba(0.0, 0.0, 0.0, 1.0 - 2.0 * brightness),
+        WheelMode::Hsl => Color::rgba(1.0, 1.0, 1.0, 2.0 * brightness - 1.0),
+    };
+    (!full_brightness && overlay.a > 0).then_some(overlay)
+}
+
+/// RGB of a wheel position in the given color model.
+fn wheel_color(mode: WheelMode, hue: f64, sat: f64, value: f64) -> (f64, f64, f64) {
+    match mode {
+        WheelMode::Hsb => math::hsb_to_rgb(hue, sat, value),
+        WheelMode::Hsl => {
+            let (h, s, v) = math::hsl_to_hsb(hue, sat, value);
+            math::hsb_to_rgb(h, s, v)
+        }
+    }
 }
 
 enum WheelUpdate {
     HueSat(f64, f64),
     Brightness(f64),
+    /// A finished background raster: side length and RGBA8 pixels.
+    Raster(u32, Vec<u8>),
+    #[cfg(feature = "image")]
+    Heatmap(Option<Rc<Heatmap>>),
 }
 
 pub(crate) struct ColorWheel {
     id: ViewId,
     held: bool,
+    /// In precise mode (Alt held): pointer and cursor positions when Alt
+    /// was engaged.
+    precise_anchor: Option<(Point, Point)>,
+    /// Whether the control has keyboard focus (draws the focus ring).
+    focused: bool,
     hue: f64,
     saturation: f64,
     brightness: f64,
     size: floem::taffy::prelude::Size<f32>,
+    theme: Theme,
     on_change: Option<Box<dyn Fn(f64, f64)>>,
+    /// Hues the pointer snaps to, if restricted.
+    allowed_hues: Option<Vec<f64>>,
+    /// Scroll step sizes for hue and saturation.
+    steps: Steps,
+    /// Whether radius/value are HSB saturation/brightness or HSL saturation/lightness.
+    mode: WheelMode,
+    /// Always draw the wheel at full brightness, without the darkening
+    /// (or, in HSL, lightening) overlay.
+    full_brightness: bool,
+    /// Hue/saturation density of an imported image, drawn over the wheel.
+    #[cfg(feature = "image")]
+    heatmap: Option<Rc<Heatmap>>,
     /// Cached full-brightness wheel image, rasterized once at a fixed resolution.
+    /// Holds a low-res placeholder until the background raster arrives.
     wheel_img: Option<peniko::Image>,
     wheel_hash: Vec<u8>,
+    /// Cancels the in-flight background raster, if any.
+    raster_cancel: Option<Arc<AtomicBool>>,
 }
 
 /// Creates a circular color wheel.
 ///
 /// - `hue`: 0.0–1.0 (angle around the wheel)
 /// - `saturation`: 0.0 (center) to 1.0 (edge)
-/// - `brightness`: read-only, used for the darkening overlay
+/// - `brightness`: read-only, used for the darkening overlay (lightness in HSL mode)
+/// - `allowed_hues`: if set, picked hues snap to the nearest entry
+/// - `theme`: cursor outline colors
+/// - `steps`: hue (vertical) and saturation (horizontal) scroll steps
+/// - `mode`: HSB or HSL; `saturation`/`brightness` must be in the same model
+/// - `full_brightness`: skip the brightness overlay; the cursor still shows
+///   the actual color
+/// - `quantize`: snap emitted hue to whole degrees and saturation to whole percent
+#[allow(clippy::too_many_arguments)]
 pub(crate) fn color_wheel(
     hue: RwSignal<f64>,
     saturation: RwSignal<f64>,
     brightness: RwSignal<f64>,
+    allowed_hues: Option<Vec<f64>>,
+    theme: Theme,
+    steps: Steps,
+    mode: WheelMode,
+    full_brightness: bool,
+    quantize: bool,
 ) -> ColorWheel {
     let id = ViewId::new();
 
@@ -119,16 +228,31 @@ pub(crate) fn color_wheel(
     ColorWheel {
         id,
         held: false,
+        precise_anchor: None,
+        focused: false,
         hue: hue.get_untracked(),
         saturation: saturation.get_untracked(),
         brightness: brightness.get_untracked(),
         size: Default::default(),
+        theme,
         on_change: Some(Box::new(move |h, s| {
-            hue.set(h);
-            saturation.set(s);
+            if quantize {
+                hue.set(math::quantize(h, 360.0).rem_euclid(1.0));
+                saturation.set(math::quantize(s, 100.0));
+            } else {
+                hue.set(h);
+                saturation.set(s);
+            }
         })),
+        allowed_hues,
+        steps,
+        mode,
+        full_brightness,
+        #[cfg(feature = "image")]
+        heatmap: None,
         wheel_img: None,
         wheel_hash: Vec::new(),
+        raster_cancel: None,
     }
     .style(|s| {
         s.flex_grow(1.0)
@@ -136,9 +260,20 @@ pub(crate) fn color_wheel(
             .min_height(100.0)
             .cursor(floem::style::CursorStyle::Default)
     })
+    .keyboard_navigable()
 }
 
 impl ColorWheel {
+    /// Overlay the density held in `heatmap`, if any.
+    #[cfg(feature = "image")]
+    pub(crate) fn heatmap(self, heatmap: RwSignal<Option<Rc<Heatmap>>>) -> Self {
+        let id = self.id;
+        create_effect(move |_| {
+            id.update_state(WheelUpdate::Heatmap(heatmap.get()));
+        });
+        self
+    }
+
     /// Side length of the square region used for the wheel.
     fn side(&self) -> f64 {
         let w = self.size.width as f64;
@@ -163,6 +298,26 @@ impl ColorWheel {
         Rect::new(cx - r, cy - r, cx + r, cy + r)
     }
 
+    /// Map the pointer to a cursor target. With Alt held the cursor moves
+    /// relative to where Alt was engaged at [`PRECISE_GAIN`]; releasing Alt
+    /// returns to absolute positioning.
+    fn pointer_target(&mut self, pos: Point, modifiers: floem::keyboard::Modifiers) -> Point {
+        if !modifiers.contains(floem::keyboard::Modifiers::ALT) {
+            self.precise_anchor = None;
+            return pos;
+        }
+        let (pointer_anchor, cursor_anchor) = match self.precise_anchor {
+            Some(anchor) => anchor,
+            None => {
+                let (x, y) = self.cursor_position();
+                let anchor = (pos, Point::new(x, y));
+                self.precise_anchor = Some(anchor);
+                anchor
+            }
+        };
+        scaled_position(cursor_anchor, pointer_anchor, pos, PRECISE_GAIN)
+    }
+
     fn update_from_pointer(&mut self, pos: Point) {
         let (cx, cy) = self.center();
         let max_r = self.radius();
@@ -182,6 +337,9 @@ impl ColorWheel {
         if h < 0.0 {
             h += 1.0;
         }
+        if let Some(allowed) = &self.allowed_hues {
+            h = math::snap_hue(h, allowed);
+        }
 
         self.hue = h;
         self.saturation = sat;
@@ -197,21 +355,63 @@ impl ColorWheel {
 
     /// Rasterize at a fixed resolution,
     /// then scale raster image to widget size.
+    ///
+    /// A small placeholder is rasterized synchronously so the first paint
+    /// shows a wheel; the full-resolution raster is computed on a background
+    /// thread and swapped in through [`WheelUpdate::Raster`].
     fn ensure_wheel_image(&mut self) {
         if self.wheel_img.is_some() {
             return;
         }
 
+        let size = constants::WHEEL_PLACEHOLDER_SIZE;
+        if let Some(pixels) = rasterize_wheel_base(size, size, self.mode, &AtomicBool::new(false)) {
+            self.set_wheel_image(size, pixels);
+        }
+        self.spawn_raster();
+    }
+
+    /// Start rasterizing at [`constants::WHEEL_RASTER_SIZE`] off the UI
+    /// thread. The raster is cancelled if the wheel is dropped first.
+    fn spawn_raster(&mut self) {
+        let cancel = Arc::new(AtomicBool::new(false));
+        self.raster_cancel = Some(cancel.clone());
+
+        let id = self.id;
+        let mode = self.mode;
         let size = constants::WHEEL_RASTER_SIZE;
-        let pixels = rasterize_wheel_base(size, size);
+        // Delivered even when cancelled so the scope is always disposed
+        let scope = Scope::new();
+        let deliver = create_ext_action(scope, move |pixels: Option<Vec<u8>>| {
+            if let Some(pixels) = pixels {
+                id.update_state(WheelUpdate::Raster(size, pixels));
+            }
+            scope.dispose();
+        });
+        std::thread::spawn(move || deliver(rasterize_wheel_base(size, size, mode, &cancel)));
+    }
+
+    fn set_wheel_image(&mut self, size: u32, pixels: Vec<u8>) {
         let blob = Blob::new(Arc::new(pixels));
         let img = peniko::Image::new(blob, peniko::Format::Rgba8, size, size);
 
-        self.wheel_hash = b"wheel".to_vec();
+        let tag: &[u8] = match self.mode {
+            WheelMode::Hsb => b"wheel",
+            WheelMode::Hsl => b"wheel-hsl",
+        };
+        self.wheel_hash = [tag, &size.to_le_bytes()].concat();
         self.wheel_img = Some(img);
     }
 }
 
+impl Drop for ColorWheel {
+    fn drop(&mut self) {
+        if let Some(cancel) = &self.raster_cancel {
+            cancel.store(true, Ordering::Relaxed);
+        }
+    }
+}
+
 impl View for ColorWheel {
     fn id(&self) -> ViewId {
         self.id
@@ -227,6 +427,14 @@ impl View for ColorWheel {
                 WheelUpdate::Brightness(b) => {
                     self.brightness = b;
                 }
+                WheelUpdate::Raster(size, pixels) => {
+                    self.raster_cancel = None;
+                    self.set_wheel_image(size, pixels);
+                }
+                #[cfg(feature = "image")]
+                WheelUpdate::Heatmap(heatmap) => {
+                    self.heatmap = heatmap;
+                }
             }
             self.id.request_layout();
         }
@@ -237,7 +445,9 @@ impl View for ColorWheel {
             Event::PointerDown(e) => {
                 cx.update_active(self.id());
                 self.held = true;
-                self.update_from_pointer(e.pos);
+                self.precise_anchor = None;
+                let target = self.pointer_target(e.pos, e.modifiers);
+                self.update_from_pointer(target);
                 if let Some(cb) = &self.on_change {
                     cb(self.hue, self.saturation);
                 }
@@ -246,7 +456,8 @@ impl View for ColorWheel {
             }
             Event::PointerMove(e) => {
                 if self.held {
-                    self.update_from_pointer(e.pos);
+                    let target = self.pointer_target(e.pos, e.modifiers);
+                    self.update_from_pointer(target);
                     if let Some(cb) = &self.on_change {
                         cb(self.hue, self.saturation);
                     }
@@ -260,10 +471,29 @@ impl View for ColorWheel {
                 self.held = false;
                 EventPropagation::Continue
             }
+            Event::FocusGained => {
+                self.focused = true;
+                self.id.request_paint();
+                EventPropagation::Continue
+            }
             Event::FocusLost => {
                 self.held = false;
+                self.focused = false;
+                self.id.request_paint();
                 EventPropagation::Continue
             }
+            Event::PointerWheel(e) => {
+                let large = e.modifiers.contains(floem::keyboard::Modifiers::SHIFT);
+                let dh = self.steps.wheel_hue.for_scroll(e.delta.y, large);
+                let ds = self.steps.wheel_saturation.for_scroll(e.delta.x, large);
+                self.hue = (self.hue + dh).rem_euclid(1.0);
+                self.saturation = (self.saturation + ds).clamp(0.0, 1.0);
+                if let Some(cb) = &self.on_change {
+                    cb(self.hue, self.saturation);
+                }
+                self.id.request_layout();
+                EventPropagation::Stop
+            }
             _ => EventPropagation::Continue,
         }
     }
@@ -302,11 +532,19 @@ impl View for ColorWheel {
         }
         cx.restore();
 
-        // Brightness overlay: darken the wheel with semi-transparent black
-        let overlay_alpha = 1.0 - self.brightness;
-        if overlay_alpha > 0.001 {
-            let overlay = Circle::new(center_pt, radius);
-            cx.fill(&overlay, Color::rgba(0.0, 0.0, 0.0, overlay_alpha), 0.0);
+        if let Some(overlay) = overlay_fill(self.mode, self.brightness, self.full_brightness) {
+            cx.fill(&Circle::new(center_pt, radius), overlay, 0.0);
+        }
+
+        #[cfg(feature = "image")]
+        if let Some(heatmap) = &self.heatmap {
+            // Dark dots on a light wheel, light dots once it is dimmed
+            let ink = if self.full_brightness || self.brightness > full_value(self.mode) / 2.0 {
+                Color::BLACK
+            } else {
+                Color::WHITE
+            };
+            heatmap.paint(cx, center_pt, radius, ink);
         }
 
         // Draw thumb — round to nearest pixel to avoid subpixel
@@ -316,20 +554,74 @@ impl View for ColorWheel {
         let r = constants::CURSOR_RADIUS;
         cx.fill(
             &Circle::new((cur_x, cur_y), r + 1.0),
-            Color::rgba8(0, 0, 0, 80),
+            self.theme.thumb_shadow,
             0.0,
         );
-        cx.fill(&Circle::new((cur_x, cur_y), r), Color::WHITE, 0.0);
+        cx.fill(&Circle::new((cur_x, cur_y), r), self.theme.thumb_ring, 0.0);
         cx.fill(
             &Circle::new((cur_x, cur_y), r - 2.0),
-            Color::rgba8(0, 0, 0, 150),
+            self.theme.thumb_inner_ring,
             0.0,
         );
-        let (cr, cg, cb) = math::hsb_to_rgb(self.hue, self.saturation, self.brightness);
+        let (cr, cg, cb) = wheel_color(self.mode, self.hue, self.saturation, self.brightness);
         cx.fill(
             &Circle::new((cur_x, cur_y), r - 3.0),
             Color::rgb(cr, cg, cb),
             0.0,
         );
+
+        // Focus ring just inside the wheel's rim
+        if self.focused {
+            cx.stroke(
+                &Circle::new(center_pt, radius - 1.0),
+                self.theme.focus_ring,
+                &floem::kurbo::Stroke::new(2.0),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn precise_mode_scales_pointer_travel() {
+        let cursor = Point::new(100.0, 80.0);
+        let anchor = Point::new(40.0, 40.0);
+        let p = scaled_position(cursor, anchor, Point::new(80.0, 20.0), PRECISE_GAIN);
+        assert_eq!(p, Point::new(110.0, 75.0));
+    }
+
+    #[test]
+    fn precise_mode_starts_at_the_cursor() {
+        let cursor = Point::new(100.0, 80.0);
+        let anchor = Point::new(40.0, 40.0);
+        assert_eq!(
+            scaled_position(cursor, anchor, anchor, PRECISE_GAIN),
+            cursor
+        );
+        // Unit gain is plain relative dragging
+        let p = scaled_position(cursor, anchor, Point::new(50.0, 30.0), 1.0);
+        assert_eq!(p, Point::new(110.0, 70.0));
+    }
+
+    #[test]
+    fn overlay_darkens_by_brightness() {
+        let fill = overlay_fill(WheelMode::Hsb, 0.25, false).unwrap();
+        assert_eq!((fill.r, fill.g, fill.b), (0, 0, 0));
+        assert_eq!(fill.a, 191);
+        assert_eq!(overlay_fill(WheelMode::Hsb, 1.0, false), None);
+        let light = overlay_fill(WheelMode::Hsl, 0.75, false).unwrap();
+        assert_eq!((light.r, light.a), (255, 128));
+    }
+
+    #[test]
+    fn full_brightness_wheel_has_no_overlay() {
+        for mode in [WheelMode::Hsb, WheelMode::Hsl] {
+            for b in [0.0, 0.25, 0.5, 0.9, 1.0] {
+                assert_eq!(overlay_fill(mode, b, true), None, "{mode:?} at {b}");
+            }
+        }
     }
 }
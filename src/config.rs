@@ -0,0 +1,737 @@
+//! Picker configuration.
+
+use std::fmt;
+use std::rc::Rc;
+use std::time::Duration;
+
+use floem::reactive::RwSignal;
+
+use crate::clipboard::SharedClipboard;
+use crate::color::SolidColor;
+use crate::constants;
+use crate::theme::Theme;
+
+/// Options controlling how the picker looks and behaves.
+///
+/// Start from [`PickerConfig::default()`] and override the fields you need:
+///
+/// ```rust
+/// use floem_picker::PickerConfig;
+///
+/// let config = PickerConfig {
+///     alpha_thumb_preview: true,
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Clone)]
+pub struct PickerConfig {
+    /// Fill the alpha slider thumb with the current color at its alpha,
+    /// composited over a checkerboard, instead of the neutral ring.
+    pub alpha_thumb_preview: bool,
+
+    /// Restrict hue to these values (0.0–1.0). Wheel picks and hue edits
+    /// snap to the nearest allowed hue; saturation and brightness stay free.
+    pub allowed_hues: Option<Vec<f64>>,
+
+    /// Tick marks on the brightness and alpha sliders, as values in 0.0–1.0
+    /// (e.g. `vec![0.0, 0.25, 0.5, 0.75, 1.0]`). Empty draws none.
+    pub slider_ticks: Vec<f64>,
+
+    /// How pointer position on the brightness and alpha sliders maps to the
+    /// value. The gradients and ticks follow the same curve.
+    pub slider_response: ResponseCurve,
+
+    /// Show a "fix contrast" button that adjusts the color's lightness to
+    /// reach WCAG AA (4.5:1) against this background. Also seeds the
+    /// background swatch when `background` is unset.
+    pub fix_contrast_against: Option<SolidColor>,
+
+    /// Background for the contrast tools. When set (or when
+    /// `fix_contrast_against` is), the editor shows a background swatch with
+    /// the contrast ratio; clicking the swatch opens a popover with a wheel,
+    /// brightness slider and hex field for the background.
+    pub background: Option<SolidColor>,
+
+    /// Show the APCA lightness contrast (Lc) of the color on the
+    /// background next to the WCAG 2 ratio.
+    pub show_apca: bool,
+    /// Background to check the color against. When set, the editor shows a
+    /// badge row with the live WCAG contrast ratio and the AA/AAA result
+    /// for normal and large text, following both signals.
+    pub contrast_against: Option<RwSignal<SolidColor>>,
+
+    /// Colors for the wheel cursor and slider thumbs.
+    pub theme: Theme,
+
+    /// Show a saturation ramp below the wheel at the current hue and
+    /// brightness, for precise saturation picks.
+    pub show_saturation_slider: bool,
+    /// Step sizes for arrow-key and scroll-wheel adjustments.
+    pub steps: Steps,
+    /// Color model the wheel edits: HSB saturation/brightness or HSL
+    /// saturation/lightness.
+    pub wheel_mode: WheelMode,
+    /// Show the brightness slider.
+    pub show_brightness_slider: bool,
+    /// Show the HSB input row.
+    pub show_hsb_row: bool,
+    /// Show the HSL input row.
+    pub show_hsl_row: bool,
+    /// Show the RGB input row.
+    pub show_rgb_row: bool,
+    /// Show the CMYK input row (naive device CMYK, in percent). Requires
+    /// the `cmyk` feature.
+    pub show_cmyk_row: bool,
+    /// Show the OKLCH input row: lightness and chroma in percent (100%
+    /// chroma is 0.4, as in CSS) and hue in degrees. Requires the `oklch`
+    /// feature.
+    pub show_oklch_row: bool,
+    /// Show the white-balance row: temperature and tint sliders that set
+    /// the color to `SolidColor::from_temperature_and_tint`. Requires the
+    /// `kelvin` feature.
+    pub show_white_balance_row: bool,
+    /// Unit for hue in the HSB/HSL copy output.
+    pub hue_unit: HueUnit,
+    /// Show a quick-pick strip of the macOS system color swatches.
+    /// Requires the `system-colors` feature; ignored on other platforms.
+    pub show_system_colors: bool,
+    /// How the alpha field displays and accepts values.
+    pub alpha_display: AlphaDisplay,
+    /// Restrict alpha to multiples of this increment (e.g. `0.05` for 5%
+    /// steps), in the slider and the alpha field. `None` allows any value.
+    pub alpha_snap: Option<f64>,
+    /// Show the copy buttons at the end of the hex and channel rows.
+    pub show_copy_buttons: bool,
+    /// Where the copy buttons write. Defaults to the system clipboard.
+    /// Swatch images (the `image` feature) always use the system clipboard.
+    pub clipboard: SharedClipboard,
+    /// Complete partial CSS color names typed in the hex field: the first
+    /// matching name replaces the field's caption, and Tab or Enter
+    /// accepts it.
+    pub complete_color_names: bool,
+    /// Show a button that snaps the color to the nearest web-safe color.
+    pub show_web_safe_snap: bool,
+    /// Draw the wheel at full brightness (HSL: lightness 0.5) instead of
+    /// darkening it with the brightness value. The actual color still shows
+    /// in the wheel's cursor and the swatch.
+    pub full_brightness_wheel: bool,
+    /// Overlay a hue/saturation density map of the last image dropped on
+    /// the picker on the wheel, showing where its colors cluster.
+    /// Requires the `image` feature.
+    pub show_image_heatmap: bool,
+    /// Round wheel and slider output to the precision the numeric fields
+    /// display (whole degrees, whole percent), so the swatch always matches
+    /// the numbers. Off by default for smooth dragging.
+    pub quantize_output: bool,
+    /// Which model's signals are stored directly; the other is derived.
+    pub ground_truth: GroundTruth,
+    /// Keep the color fully opaque: alpha is pinned to 1.0 (also when set
+    /// from outside or typed as 8-digit hex) and the alpha slider and
+    /// fields are hidden, even with the `alpha` feature on. Hex codes are
+    /// then always 6 digits.
+    pub opaque: bool,
+    /// Arrangement of the wheel relative to the other controls.
+    pub layout: EditorLayout,
+    /// Gaps and padding of the editor, for denser or airier pickers.
+    pub spacing: Spacing,
+    /// Show the color wheel.
+    pub show_wheel: bool,
+    /// Edit brightness and alpha together in one 2D pad (brightness across,
+    /// alpha down) instead of two sliders. Needs the `alpha` feature;
+    /// otherwise the brightness slider is shown as usual.
+    pub brightness_alpha_pad: bool,
+    /// Show the saturation, brightness and alpha sliders. When false, this
+    /// overrides `show_saturation_slider` and `show_brightness_slider`.
+    pub show_sliders: bool,
+    /// Drag on the swatch to scrub brightness (horizontal) and saturation
+    /// (vertical).
+    pub scrub_swatch: bool,
+    /// Show a sun or snowflake glyph by the swatch when the color is
+    /// clearly warm or cool (see [`SolidColor::temperature_bias`]).
+    pub show_temperature: bool,
+    /// Show a bar breaking the color down into its red, green and blue
+    /// shares.
+    pub show_channel_bar: bool,
+    /// Split the swatch into the color the picker opened with (left) and the
+    /// current color (right). Clicking the left half reverts.
+    pub compare_swatch: bool,
+    /// Called when the eyedropper samples a color outside sRGB (e.g. on a
+    /// wide-gamut display). The stored color is clamped either way.
+    pub on_out_of_gamut_sample: Option<OutOfGamutCallback>,
+    /// Values shown in the RGB row.
+    pub rgb_display: RgbDisplay,
+    /// Labels of the RGB row's fields. `None` uses "sR", "G", "B" for
+    /// [`RgbDisplay::Srgb`] and "R", "G", "B" for [`RgbDisplay::Linear`].
+    pub rgb_labels: Option<[&'static str; 3]>,
+    /// Text of the other field labels and captions, e.g. for localization.
+    pub labels: Labels,
+    /// Order of the RGB row's fields. Labels follow their channels, and
+    /// with a non-RGB order the copy button copies the values in that
+    /// order. The color itself is unaffected.
+    pub rgb_channel_order: ChannelOrder,
+    /// Update the color on every valid keystroke in the numeric fields,
+    /// rather than on Enter or blur.
+    pub live_numeric: bool,
+    /// Let the HSB, HSL and RGB field labels be dragged left or right to
+    /// scrub the value, one step per few pixels (Shift for large steps).
+    pub scrub_labels: bool,
+    /// What a text field does with its text when it loses focus because
+    /// the window was deactivated, rather than because another field or
+    /// control was focused.
+    pub window_blur_commit: WindowBlurCommit,
+    /// While a numeric field has focus, veil the wheel and sliders that
+    /// don't edit its value, showing which control maps to it.
+    pub focus_highlight: bool,
+    /// Show separate two-digit hex fields per channel (RR, GG, BB, and AA
+    /// with the `alpha` feature).
+    pub show_hex_channels_row: bool,
+    /// Recently used colors, newest first. When set, the editor records
+    /// settled colors into it and shows them as a strip of chips.
+    ///
+    /// Pass the same signal to several pickers to share one history:
+    ///
+    /// ```rust,no_run
+    /// use floem::reactive::RwSignal;
+    /// use floem_picker::PickerConfig;
+    ///
+    /// let history = RwSignal::new(Vec::new());
+    /// let config = PickerConfig {
+    ///     history: Some(history),
+    ///     ..Default::default()
+    /// };
+    /// // Use `config.clone()` for each picker.
+    /// ```
+    pub history: Option<RwSignal<Vec<SolidColor>>>,
+    /// Maximum number of colors kept in `history`.
+    pub history_len: usize,
+    /// Named colors (e.g. a brand palette). When non-empty, the editor shows
+    /// the name of a matching entry and a button to snap to the nearest one.
+    pub named_palette: Vec<(String, SolidColor)>,
+    /// Largest OKLab distance (see [`palette::delta_e`](crate::palette::delta_e))
+    /// at which a palette entry's name is shown. 0.0 requires an exact match.
+    pub palette_match_threshold: f64,
+    /// When set, the hex row gets a button copying a design-token JSON
+    /// block of the color's [Material tones](crate::palette::material_tones)
+    /// under this name (see [`palette::to_design_tokens`](crate::palette::to_design_tokens)).
+    pub design_token_name: Option<String>,
+    /// Ease the wheel and sliders toward colors set from elsewhere (the
+    /// bound signal, typed values, a [`PickerHandle`](crate::PickerHandle))
+    /// instead of jumping. The color itself still changes at once.
+    pub animate_external_changes: bool,
+    /// Length of the easing when `animate_external_changes` is on.
+    pub animation_duration: Duration,
+    /// The user prefers reduced motion (e.g. from the platform's
+    /// accessibility settings). Turns off all animation.
+    pub reduced_motion: bool,
+}
+
+impl Default for PickerConfig {
+    fn default() -> Self {
+        Self {
+            alpha_thumb_preview: false,
+            allowed_hues: None,
+            slider_ticks: Vec::new(),
+            slider_response: ResponseCurve::Linear,
+            fix_contrast_against: None,
+            show_apca: false,
+            contrast_against: None,
+            background: None,
+            theme: Theme::default(),
+            show_saturation_slider: false,
+            steps: Steps::default(),
+            wheel_mode: WheelMode::Hsb,
+            show_brightness_slider: true,
+            show_hsb_row: true,
+            show_hsl_row: true,
+            show_rgb_row: true,
+            show_cmyk_row: true,
+            show_oklch_row: true,
+            show_white_balance_row: true,
+            hue_unit: HueUnit::Degrees,
+            show_system_colors: false,
+            alpha_display: AlphaDisplay::Percent,
+            alpha_snap: None,
+            show_copy_buttons: true,
+            complete_color_names: false,
+            clipboard: SharedClipboard::default(),
+            show_web_safe_snap: false,
+            full_brightness_wheel: false,
+            show_image_heatmap: false,
+            quantize_output: false,
+            ground_truth: GroundTruth::Hsb,
+            opaque: false,
+            layout: EditorLayout::Vertical,
+            spacing: Spacing::default(),
+            show_wheel: true,
+            brightness_alpha_pad: false,
+            show_sliders: true,
+            scrub_swatch: false,
+            show_temperature: false,
+            show_channel_bar: false,
+            compare_swatch: false,
+            on_out_of_gamut_sample: None,
+            rgb_display: RgbDisplay::Srgb,
+            rgb_labels: None,
+            labels: Labels::default(),
+            rgb_channel_order: ChannelOrder::Rgb,
+            live_numeric: false,
+            scrub_labels: false,
+            window_blur_commit: WindowBlurCommit::Keep,
+            focus_highlight: false,
+            show_hex_channels_row: false,
+            history: None,
+            history_len: 12,
+            named_palette: Vec::new(),
+            palette_match_threshold: 0.02,
+            design_token_name: None,
+            animate_external_changes: false,
+            animation_duration: Duration::from_millis(180),
+            reduced_motion: false,
+        }
+    }
+}
+
+impl PickerConfig {
+    /// HSL-only preset: the wheel edits HSL saturation with a lightness
+    /// overlay, only the HSL and hex rows are shown, and HSL is the ground truth.
+    pub fn hsl_only() -> Self {
+        Self {
+            wheel_mode: WheelMode::Hsl,
+            ground_truth: GroundTruth::Hsl,
+            show_brightness_slider: false,
+            show_hsb_row: false,
+            show_rgb_row: false,
+            show_cmyk_row: false,
+            show_oklch_row: false,
+            show_white_balance_row: false,
+            ..Default::default()
+        }
+    }
+
+    /// Numeric-only preset: hex, HSB, HSL and RGB rows with the swatch, and
+    /// no wheel or sliders.
+    pub fn numeric() -> Self {
+        Self {
+            show_wheel: false,
+            show_sliders: false,
+            show_white_balance_row: false,
+            ..Default::default()
+        }
+    }
+}
+
+/// Spacing of the editor's layout, in logical pixels.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Spacing {
+    /// Gap between the wheel, sliders and rows.
+    pub gap: f32,
+    /// Padding around the whole editor (below and to the sides).
+    pub padding: f32,
+    /// Gap between the fields within a numeric row.
+    pub row_gap: f32,
+}
+
+impl Default for Spacing {
+    fn default() -> Self {
+        Self {
+            gap: constants::GAP,
+            padding: constants::PADDING,
+            row_gap: constants::GAP / 2.0,
+        }
+    }
+}
+
+/// Labels and captions of the editor's fields.
+///
+/// The RGB row's labels depend on [`RgbDisplay`] and are set separately
+/// through [`PickerConfig::rgb_labels`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Labels {
+    /// Hue field in the HSB and HSL rows. Default: "H".
+    pub hue: &'static str,
+    /// Saturation field in the HSB and HSL rows. Default: "S".
+    pub saturation: &'static str,
+    /// Brightness field in the HSB row. Default: "B".
+    pub brightness: &'static str,
+    /// Lightness field in the HSL row. Default: "L".
+    pub lightness: &'static str,
+    /// Caption under the hex field. Default: "HEX".
+    pub hex: &'static str,
+    /// CMYK fields (the `cmyk` feature). Default: "C", "M", "Y", "K".
+    pub cmyk: [&'static str; 4],
+    /// OKLCH fields (the `oklch` feature). Default: "L", "C", "H".
+    pub oklch: [&'static str; 3],
+    /// Temperature and tint sliders (the `kelvin` feature). Default:
+    /// "Temp", "Tint".
+    pub white_balance: [&'static str; 2],
+    /// Per-channel hex fields. Default: "RR", "GG", "BB", "AA".
+    pub hex_channels: [&'static str; 4],
+    /// Unit after the alpha field in [`AlphaDisplay::Percent`]. Default: "%".
+    pub percent: &'static str,
+    /// Label of the background color editor. Default: "Background".
+    pub background: &'static str,
+}
+
+impl Default for Labels {
+    fn default() -> Self {
+        Self {
+            hue: "H",
+            saturation: "S",
+            brightness: "B",
+            lightness: "L",
+            hex: "HEX",
+            cmyk: ["C", "M", "Y", "K"],
+            oklch: ["L", "C", "H"],
+            white_balance: ["Temp", "Tint"],
+            hex_channels: ["RR", "GG", "BB", "AA"],
+            percent: "%",
+            background: "Background",
+        }
+    }
+}
+
+/// How a text field handles losing focus to a window switch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WindowBlurCommit {
+    /// Commit as on any blur: valid text is applied, invalid text reverts.
+    Commit,
+    /// Apply valid text; leave invalid text in the field to finish later.
+    CommitValid,
+    /// Leave the text as typed. It commits on Enter or when the field
+    /// loses focus within the window.
+    #[default]
+    Keep,
+}
+
+impl WindowBlurCommit {
+    /// Whether a field whose text is `valid` commits on a window switch.
+    pub(crate) fn commits(self, valid: bool) -> bool {
+        match self {
+            Self::Commit => true,
+            Self::CommitValid => valid,
+            Self::Keep => false,
+        }
+    }
+}
+
+/// Arrangement of the editor's controls.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EditorLayout {
+    /// Wheel on top, sliders and rows stacked below it.
+    #[default]
+    Vertical,
+    /// Wheel on the left, sliders and rows in a column on the right. Suits
+    /// wide panels.
+    WheelLeft,
+}
+
+/// Color model edited by the wheel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WheelMode {
+    /// Radius is HSB saturation; the wheel darkens with brightness.
+    #[default]
+    Hsb,
+    /// Radius is HSL saturation; the wheel darkens or lightens with lightness.
+    Hsl,
+}
+
+/// Color model whose signals hold the picker's state.
+///
+/// The other model is derived from it. Edits in the derived model round-trip
+/// through the ground truth, so they can drift by a rounding step; edits in
+/// the ground truth are stored as entered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GroundTruth {
+    /// HSB is stored; HSL is derived.
+    #[default]
+    Hsb,
+    /// HSL is stored; HSB is derived. Keeps HSL saturation and lightness
+    /// stable across edits, including at black and white.
+    Hsl,
+}
+
+/// Callback receiving the unclamped sRGB components `(r, g, b)` of an
+/// eyedropper sample that fell outside 0.0–1.0.
+#[derive(Clone)]
+pub struct OutOfGamutCallback(pub Rc<dyn Fn(f64, f64, f64)>);
+
+impl OutOfGamutCallback {
+    /// Wrap `f`, which receives the unclamped `(r, g, b)` of the sample.
+    pub fn new(f: impl Fn(f64, f64, f64) + 'static) -> Self {
+        Self(Rc::new(f))
+    }
+}
+
+impl fmt::Debug for OutOfGamutCallback {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("OutOfGamutCallback(..)")
+    }
+}
+
+/// Values shown in the RGB row, both on a 0–255 scale.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RgbDisplay {
+    /// Gamma-encoded sRGB channels, as in hex codes.
+    #[default]
+    Srgb,
+    /// Linear-light channels (sRGB transfer function removed).
+    Linear,
+}
+
+/// Order of the fields in the RGB row.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ChannelOrder {
+    /// Red, green, blue.
+    #[default]
+    Rgb,
+    /// Blue, green, red.
+    Bgr,
+}
+
+impl ChannelOrder {
+    /// Indices into `[r, g, b]` in display order.
+    pub fn indices(self) -> [usize; 3] {
+        match self {
+            ChannelOrder::Rgb => [0, 1, 2],
+            ChannelOrder::Bgr => [2, 1, 0],
+        }
+    }
+}
+
+/// Display format of the alpha field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AlphaDisplay {
+    /// Whole percent, 0–100.
+    #[default]
+    Percent,
+    /// Two hex digits, 00–FF, matching the hex field's alpha byte.
+    HexByte,
+}
+
+/// Unit used when writing out a hue.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HueUnit {
+    /// Whole degrees, 0–360 (e.g. `217`).
+    #[default]
+    Degrees,
+    /// Fraction of a turn, 0–1 (e.g. `0.603`).
+    Turns,
+    /// Radians, 0–2π (e.g. `3.789`).
+    Radians,
+}
+
+impl HueUnit {
+    /// Format a normalized 0.0–1.0 hue in this unit.
+    pub(crate) fn format(&self, hue: f64) -> String {
+        match self {
+            Self::Degrees => format!("{}", (hue * 360.0).round() as i64),
+            Self::Turns => format!("{:.3}", hue),
+            Self::Radians => format!("{:.3}", hue * std::f64::consts::TAU),
+        }
+    }
+
+    /// Like [`format`](Self::format), with the CSS unit suffix for non-degree units.
+    pub(crate) fn css(&self, hue: f64) -> String {
+        match self {
+            Self::Degrees => self.format(hue),
+            Self::Turns => format!("{}turn", self.format(hue)),
+            Self::Radians => format!("{}rad", self.format(hue)),
+        }
+    }
+}
+
+/// A step size with a larger variant used while Shift is held.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StepSize {
+    /// Step without modifiers.
+    pub normal: f64,
+    /// Step while Shift is held.
+    pub large: f64,
+}
+
+impl StepSize {
+    /// Create a step size.
+    pub const fn new(normal: f64, large: f64) -> Self {
+        Self { normal, large }
+    }
+
+    /// The step to apply, `large` when Shift is held.
+    pub(crate) fn get(&self, large: bool) -> f64 {
+        if large { self.large } else { self.normal }
+    }
+
+    /// Signed step for a scroll of `delta`; scrolling up (negative delta)
+    /// increases the value.
+    pub(crate) fn for_scroll(&self, delta: f64, large: bool) -> f64 {
+        if delta < 0.0 {
+            self.get(large)
+        } else if delta > 0.0 {
+            -self.get(large)
+        } else {
+            0.0
+        }
+    }
+}
+
+/// Mapping from a slider's track position to its value.
+///
+/// Positions and values are both 0.0–1.0, measured from the end of the
+/// track where the value is 0.0. The non-linear curves spread low values
+/// over more of the track, making the bottom few percent easier to hit.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum ResponseCurve {
+    /// Value equals position.
+    #[default]
+    Linear,
+    /// Value grows exponentially with position: a pointer movement at the
+    /// top of the track changes the value 100× as much as at the bottom.
+    Log,
+    /// Value is position raised to this exponent. Exponents above 1.0 give
+    /// more resolution to low values, below 1.0 to high values.
+    Gamma(f64),
+}
+
+/// Ratio of [`ResponseCurve::Log`]'s slope at the top of the track to its
+/// slope at the bottom.
+const LOG_RANGE: f64 = 100.0;
+
+impl ResponseCurve {
+    /// The value at track `position`.
+    pub fn value_at(self, position: f64) -> f64 {
+        let p = position.clamp(0.0, 1.0);
+        match self {
+            Self::Linear => p,
+            Self::Log => (LOG_RANGE.powf(p) - 1.0) / (LOG_RANGE - 1.0),
+            Self::Gamma(g) if g > 0.0 => p.powf(g),
+            Self::Gamma(_) => p,
+        }
+    }
+
+    /// The track position of `value`; the inverse of [`value_at`](Self::value_at).
+    pub fn position_of(self, value: f64) -> f64 {
+        let v = value.clamp(0.0, 1.0);
+        match self {
+            Self::Linear => v,
+            Self::Log => (1.0 + v * (LOG_RANGE - 1.0)).ln() / LOG_RANGE.ln(),
+            Self::Gamma(g) if g > 0.0 => v.powf(g.recip()),
+            Self::Gamma(_) => v,
+        }
+    }
+
+    /// Distinguishes curves in raster cache keys.
+    pub(crate) fn cache_key(self) -> [u8; 9] {
+        let (tag, param) = match self {
+            Self::Linear => (0, 0.0),
+            Self::Log => (1, 0.0),
+            Self::Gamma(g) => (2, g),
+        };
+        let mut key = [tag; 9];
+        key[1..].copy_from_slice(&param.to_le_bytes());
+        key
+    }
+}
+
+/// Step sizes for every control that can be nudged by keyboard or scroll.
+///
+/// Wheel and slider steps are in normalized 0.0–1.0 units; numeric field
+/// steps are in the field's display units (degrees, percent, or 0–255).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Steps {
+    /// Wheel hue per scroll notch. Default: 1° / 10°.
+    pub wheel_hue: StepSize,
+    /// Wheel saturation per horizontal scroll notch. Default: 1% / 10%.
+    pub wheel_saturation: StepSize,
+    /// Brightness and alpha sliders per scroll notch. Default: 1% / 10%.
+    pub slider: StepSize,
+    /// Numeric fields per Up/Down arrow press. Default: 1 / 10.
+    pub numeric: StepSize,
+}
+
+impl Default for Steps {
+    fn default() -> Self {
+        Self {
+            wheel_hue: StepSize::new(1.0 / 360.0, 10.0 / 360.0),
+            wheel_saturation: StepSize::new(0.01, 0.1),
+            slider: StepSize::new(0.01, 0.1),
+            numeric: StepSize::new(1.0, 10.0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn step_size_uses_large_step_with_shift() {
+        let step = StepSize::new(0.5, 4.0);
+        assert_eq!(step.get(false), 0.5);
+        assert_eq!(step.get(true), 4.0);
+    }
+
+    #[test]
+    fn scroll_up_increases_by_the_configured_step() {
+        let step = StepSize::new(0.02, 0.2);
+        assert_eq!(step.for_scroll(-1.0, false), 0.02);
+        assert_eq!(step.for_scroll(-30.0, true), 0.2);
+        assert_eq!(step.for_scroll(1.0, false), -0.02);
+        assert_eq!(step.for_scroll(30.0, true), -0.2);
+        assert_eq!(step.for_scroll(0.0, true), 0.0);
+    }
+
+    #[test]
+    fn default_steps_match_the_docs() {
+        let steps = Steps::default();
+        assert!((steps.wheel_hue.get(false) * 360.0 - 1.0).abs() < 1e-9);
+        assert!((steps.wheel_hue.get(true) * 360.0 - 10.0).abs() < 1e-9);
+        assert_eq!(steps.slider, StepSize::new(0.01, 0.1));
+        assert_eq!(steps.numeric, StepSize::new(1.0, 10.0));
+        assert_eq!(PickerConfig::default().steps, steps);
+    }
+
+    #[test]
+    fn hue_unit_formats_a_known_hue() {
+        assert_eq!(HueUnit::Degrees.format(0.5), "180");
+        assert_eq!(HueUnit::Turns.format(0.5), "0.500");
+        assert_eq!(HueUnit::Radians.format(0.5), "3.142");
+    }
+
+    #[test]
+    fn hue_unit_css_suffixes() {
+        let hsl = |unit: HueUnit| crate::color::hue_function("hsl", &unit.css(0.5), 1.0, 0.5, None);
+        assert_eq!(hsl(HueUnit::Degrees), "hsl(180, 100%, 50%)");
+        assert_eq!(hsl(HueUnit::Turns), "hsl(0.500turn, 100%, 50%)");
+        assert_eq!(hsl(HueUnit::Radians), "hsl(3.142rad, 100%, 50%)");
+    }
+
+    const CURVES: [ResponseCurve; 5] = [
+        ResponseCurve::Linear,
+        ResponseCurve::Log,
+        ResponseCurve::Gamma(2.2),
+        ResponseCurve::Gamma(0.5),
+        ResponseCurve::Gamma(0.0),
+    ];
+
+    #[test]
+    fn position_of_inverts_value_at() {
+        for curve in CURVES {
+            for i in 0..=20 {
+                let p = i as f64 / 20.0;
+                let back = curve.position_of(curve.value_at(p));
+                assert!((back - p).abs() < 1e-9, "{curve:?} at {p}: {back}");
+            }
+        }
+    }
+
+    #[test]
+    fn curves_keep_the_ends_and_clamp() {
+        for curve in CURVES {
+            assert!(curve.value_at(0.0).abs() < 1e-12, "{curve:?}");
+            assert!((curve.value_at(1.0) - 1.0).abs() < 1e-12, "{curve:?}");
+            assert_eq!(curve.value_at(-1.0), curve.value_at(0.0));
+            assert_eq!(curve.position_of(2.0), curve.position_of(1.0));
+        }
+        // Log spreads low values over more of the track
+        assert!(ResponseCurve::Log.position_of(0.05) > 0.3);
+    }
+}
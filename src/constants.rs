@@ -30,17 +30,48 @@ pub(crate) const INPUT_FONT: f32 = 11.0;
 /// Label font size
 pub(crate) const LABEL_FONT: f32 = 10.0;
 
+/// Gray level (0–255) of the picker's panel background
+pub(crate) const PANEL_GRAY: u8 = 242;
+
+/// WCAG AA contrast ratio for normal text
+pub(crate) const CONTRAST_AA: f64 = 4.5;
+
+/// WCAG AAA contrast ratio for normal text
+pub(crate) const CONTRAST_AAA: f64 = 7.0;
+
+/// WCAG AA contrast ratio for large text (18pt, or 14pt bold)
+pub(crate) const CONTRAST_AA_LARGE: f64 = 3.0;
+
+/// WCAG AAA contrast ratio for large text
+pub(crate) const CONTRAST_AAA_LARGE: f64 = 4.5;
+
 /// Fixed raster size (in pixels) for the color wheel and slider gradients.
 /// Rasterized once and scaled by the renderer, avoiding new texture-atlas
 /// entries on every resize (which exhausts vger's fixed-size atlas).
 pub(crate) const WHEEL_RASTER_SIZE: u32 = 1024;
 
+/// Raster size of the wheel placeholder drawn synchronously while the
+/// full-resolution raster is computed in the background.
+pub(crate) const WHEEL_PLACEHOLDER_SIZE: u32 = 128;
+
 /// Fixed raster width for slider gradients.
 pub(crate) const SLIDER_RASTER_WIDTH: u32 = 256;
 
 /// Fixed raster height for slider gradients.
 pub(crate) const SLIDER_RASTER_HEIGHT: u32 = 32;
 
+/// Gray levels (0–255) of the light and dark checkerboard cells
+pub(crate) const CHECKER_LIGHT: u8 = 255;
+pub(crate) const CHECKER_DARK: u8 = 204;
+
+/// Height of the combined brightness and alpha pad
+#[cfg(feature = "alpha")]
+pub(crate) const PAD_HEIGHT: f32 = 64.0;
+
+/// Fixed raster height for the brightness and alpha pad.
+#[cfg(feature = "alpha")]
+pub(crate) const PAD_RASTER_HEIGHT: u32 = 64;
+
 /// Checkerboard cell size (for alpha backgrounds)
 #[cfg(feature = "alpha")]
 pub(crate) const CHECKER_CELL: f64 = 5.0;
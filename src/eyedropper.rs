@@ -1,93 +1,60 @@
-//! macOS native eyedropper (screen color sampler) integration.
+//! Eyedropper (screen color sampler) button.
 //!
-//! Uses `NSColorSampler` via Objective-C FFI to call the system
-//! screen color picker (asynchronously).
-//! Windows and Linux are not supported.
-
-use std::cell::Cell;
-
-use block2::RcBlock;
-use objc2::rc::{Allocated, Id};
-use objc2::runtime::{AnyClass, AnyObject};
-use objc2::{msg_send, msg_send_id};
+//! Sampling is platform-specific; each platform module provides a
+//! `sample_color` with the same signature:
+//! - macOS: `NSColorSampler` ([`eyedropper_macos`](crate::eyedropper_macos)).
+//! - Linux (X11): a pointer grab on the root window
+//!   ([`eyedropper_x11`](crate::eyedropper_x11)).
+//! - Linux (Wayland, with the `wayland` feature): the XDG desktop portal's
+//!   `PickColor` ([`eyedropper_wayland`](crate::eyedropper_wayland)).
+//! - Windows: low-level input hooks and GDI `GetPixel`
+//!   ([`eyedropper_win`](crate::eyedropper_win)).
 
 use floem::prelude::*;
 use floem::reactive::{RwSignal, SignalUpdate};
 
 use crate::color::SolidColor;
+use crate::config::OutOfGamutCallback;
+#[cfg(target_os = "macos")]
+use crate::eyedropper_macos::sample_color;
+#[cfg(target_os = "windows")]
+use crate::eyedropper_win::sample_color;
+use crate::icon_font;
 
-/// Invokes the macOS native screen color sampler.
-///
-/// When the user picks a pixel, `on_pick` is called with the sampled color
-/// (converted to sRGB). If the user cancels (Esc), nothing happens.
-///
-/// Must be called from the main thread (Floem event handlers satisfy this).
-pub(crate) fn sample_color(on_pick: impl FnOnce(SolidColor) + 'static) {
-    let cls = match AnyClass::get("NSColorSampler") {
-        Some(c) => c,
-        None => return,
-    };
-
-    let sampler: Allocated<AnyObject> = unsafe { msg_send_id![cls, alloc] };
-    let sampler: Id<AnyObject> = unsafe { msg_send_id![sampler, init] };
-
-    type Callback = Cell<Option<Box<dyn FnOnce(SolidColor)>>>;
-    let callback: Callback = Cell::new(Some(Box::new(on_pick)));
-
-    let block = RcBlock::new(move |color_ptr: *mut AnyObject| {
-        if color_ptr.is_null() {
-            return;
-        }
-        unsafe {
-            let ns_cs_cls = match AnyClass::get("NSColorSpace") {
-                Some(c) => c,
-                None => return,
-            };
-            let srgb: *const AnyObject = msg_send![ns_cs_cls, sRGBColorSpace];
-            if srgb.is_null() {
-                return;
-            }
-            let srgb_color: *const AnyObject = msg_send![&*color_ptr, colorUsingColorSpace: &*srgb];
-            if srgb_color.is_null() {
-                return;
-            }
-            let mut r: f64 = 0.0;
-            let mut g: f64 = 0.0;
-            let mut b: f64 = 0.0;
-            let mut a: f64 = 0.0;
-            let _: () = msg_send![
-                &*srgb_color,
-                getRed: &mut r,
-                green: &mut g,
-                blue: &mut b,
-                alpha: &mut a
-            ];
-            if let Some(cb) = callback.take() {
-                cb(SolidColor::from_rgba(r, g, b, a));
-            }
-        }
-    });
-
-    unsafe {
-        let _: () = msg_send![&*sampler, showSamplerWithSelectionHandler: &*block];
+/// The Wayland portal sampler in Wayland sessions (with the `wayland`
+/// feature), the X11 one otherwise.
+#[cfg(target_os = "linux")]
+fn sample_color(on_pick: impl FnOnce(SolidColor, Option<(f64, f64, f64)>) + 'static) {
+    #[cfg(feature = "wayland")]
+    if crate::eyedropper_wayland::is_wayland_session() {
+        crate::eyedropper_wayland::sample_color(on_pick);
+        return;
     }
+    crate::eyedropper_x11::sample_color(on_pick);
 }
 
-/// Pipette button that calls `NSColorSampler`.
+/// Pipette button that calls the platform's screen color sampler.
 ///
-/// On click, opens the system eyedropper for input.
-/// The picked color is then written to `color`.
-pub(crate) fn eyedropper_button(color: RwSignal<SolidColor>) -> impl IntoView {
+/// On click, opens the eyedropper for input.
+/// The picked color is then written to `color`, clamped to sRGB;
+/// `on_out_of_gamut` is told when the sample was outside sRGB.
+///
+/// If the picker was dropped while the sampler was open, `color` has been
+/// disposed; the pick is then discarded and `on_out_of_gamut` is not called.
+pub(crate) fn eyedropper_button(
+    color: RwSignal<SolidColor>,
+    on_out_of_gamut: Option<OutOfGamutCallback>,
+) -> impl IntoView {
     let pressed = RwSignal::new(false);
-    label(|| lucide_icons::Icon::Pipette.unicode().to_string())
+    label(|| icon_font::text(lucide_icons::Icon::Pipette))
         .style(move |s| {
             let c = if pressed.get() {
                 Color::rgb8(80, 80, 80)
             } else {
                 Color::rgb8(120, 120, 120)
             };
-            s.font_size(18.0)
-                .font_family("lucide".to_string())
+            icon_font::font(s)
+                .font_size(18.0)
                 .cursor(floem::style::CursorStyle::Pointer)
                 .border_radius(3.0)
                 .padding(2.0)
@@ -99,8 +66,81 @@ pub(crate) fn eyedropper_button(color: RwSignal<SolidColor>) -> impl IntoView {
         })
         .on_event_stop(floem::event::EventListener::PointerUp, move |_| {
             pressed.set(false);
-            sample_color(move |picked| {
-                color.set(picked);
+            let on_out_of_gamut = on_out_of_gamut.clone();
+            sample_color(move |picked, unclamped| {
+                apply_pick(color, on_out_of_gamut.as_ref(), picked, unclamped);
             });
         })
 }
+
+/// Write a sampled color to `color` and report an out-of-sRGB sample to
+/// `on_out_of_gamut`. Does nothing once `color` has been disposed.
+fn apply_pick(
+    color: RwSignal<SolidColor>,
+    on_out_of_gamut: Option<&OutOfGamutCallback>,
+    picked: SolidColor,
+    unclamped: Option<(f64, f64, f64)>,
+) {
+    // `None` once the signal's scope has been disposed
+    if color.try_update(|c| *c = picked).is_none() {
+        return;
+    }
+    if let (Some(cb), Some((r, g, b))) = (on_out_of_gamut, unclamped) {
+        (cb.0)(r, g, b);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    use floem::reactive::{Scope, SignalGet};
+
+    /// Last unclamped `(r, g, b)` reported to a [`recorder`] callback.
+    type Seen = Rc<Cell<Option<(f64, f64, f64)>>>;
+
+    fn recorder() -> (OutOfGamutCallback, Seen) {
+        let seen = Rc::new(Cell::new(None));
+        let sink = seen.clone();
+        let cb = OutOfGamutCallback::new(move |r, g, b| sink.set(Some((r, g, b))));
+        (cb, seen)
+    }
+
+    #[test]
+    fn out_of_gamut_sample_is_clamped_and_reported() {
+        let color = RwSignal::new(SolidColor::from_rgb(0, 0, 0));
+        let (cb, seen) = recorder();
+        let picked = SolidColor::from_rgba(1.0, 0.0, 0.0, 1.0);
+        apply_pick(color, Some(&cb), picked, Some((1.09, -0.23, -0.15)));
+        assert_eq!(color.get(), picked);
+        assert_eq!(seen.get(), Some((1.09, -0.23, -0.15)));
+    }
+
+    #[test]
+    fn in_gamut_sample_is_not_reported() {
+        let color = RwSignal::new(SolidColor::from_rgb(0, 0, 0));
+        let (cb, seen) = recorder();
+        let picked = SolidColor::from_rgb(0x3B, 0x82, 0xF6);
+        apply_pick(color, Some(&cb), picked, None);
+        assert_eq!(color.get(), picked);
+        assert_eq!(seen.get(), None);
+    }
+
+    #[test]
+    fn pick_after_dispose_is_discarded() {
+        let scope = Scope::new();
+        let color = scope.create_rw_signal(SolidColor::from_rgb(0, 0, 0));
+        scope.dispose();
+        let (cb, seen) = recorder();
+        apply_pick(
+            color,
+            Some(&cb),
+            SolidColor::from_rgb(255, 0, 0),
+            Some((1.09, -0.23, -0.15)),
+        );
+        assert_eq!(seen.get(), None);
+    }
+}
@@ -0,0 +1,66 @@
+//! macOS native eyedropper (screen color sampler) integration.
+//!
+//! Uses `NSColorSampler` via Objective-C FFI to call the system
+//! screen color picker (asynchronously).
+
+use std::cell::Cell;
+
+use block2::RcBlock;
+use objc2::rc::{Allocated, Id};
+use objc2::runtime::{AnyClass, AnyObject};
+use objc2::{msg_send, msg_send_id};
+
+use crate::color::SolidColor;
+use crate::ns_color::ns_color_to_srgb;
+
+/// Tolerance for float noise from the color-space conversion.
+const GAMUT_EPS: f64 = 1e-4;
+
+/// Whether any sRGB component falls outside 0.0–1.0 (beyond float noise).
+fn is_out_of_srgb(r: f64, g: f64, b: f64) -> bool {
+    [r, g, b]
+        .iter()
+        .any(|&c| !(-GAMUT_EPS..=1.0 + GAMUT_EPS).contains(&c))
+}
+
+/// Invokes the macOS native screen color sampler.
+///
+/// When the user picks a pixel, `on_pick` is called with the sampled color
+/// converted to sRGB, plus the unclamped components if the sample was
+/// outside sRGB (e.g. on a wide-gamut display). The color passed is always
+/// clamped to sRGB. If the user cancels (Esc), nothing happens.
+///
+/// Must be called from the main thread (Floem event handlers satisfy this).
+///
+/// The sampler overlay outlives the click that opened it, so the picker
+/// may be torn down before `on_pick` runs. Callers must not assume their
+/// signals are still alive; see
+/// [`eyedropper_button`](crate::eyedropper::eyedropper_button).
+pub(crate) fn sample_color(on_pick: impl FnOnce(SolidColor, Option<(f64, f64, f64)>) + 'static) {
+    let cls = match AnyClass::get("NSColorSampler") {
+        Some(c) => c,
+        None => return,
+    };
+
+    let sampler: Allocated<AnyObject> = unsafe { msg_send_id![cls, alloc] };
+    let sampler: Id<AnyObject> = unsafe { msg_send_id![sampler, init] };
+
+    type Callback = Cell<Option<Box<dyn FnOnce(SolidColor, Option<(f64, f64, f64)>)>>>;
+    let callback: Callback = Cell::new(Some(Box::new(on_pick)));
+
+    let block = RcBlock::new(move |color_ptr: *mut AnyObject| {
+        if color_ptr.is_null() {
+            return;
+        }
+        if let Some((r, g, b, a)) = unsafe { ns_color_to_srgb(&*color_ptr) }
+            && let Some(cb) = callback.take()
+        {
+            let unclamped = is_out_of_srgb(r, g, b).then_some((r, g, b));
+            cb(SolidColor::from_rgba(r, g, b, a), unclamped);
+        }
+    });
+
+    unsafe {
+        let _: () = msg_send![&*sampler, showSamplerWithSelectionHandler: &*block];
+    }
+}
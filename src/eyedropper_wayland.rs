@@ -0,0 +1,50 @@
+//! Wayland screen color sampler through the XDG desktop portal.
+//!
+//! Calls `org.freedesktop.portal.Screenshot.PickColor`, which lets the
+//! compositor run its own picking UI. The D-Bus round trip is awaited on a
+//! background thread, so the UI keeps drawing meanwhile.
+
+use ashpd::desktop::Color;
+use floem::ext_event::create_ext_action;
+use floem::reactive::Scope;
+
+use crate::color::SolidColor;
+
+/// Whether the app runs in a Wayland session, where the X11 sampler can't
+/// see other windows.
+pub(crate) fn is_wayland_session() -> bool {
+    std::env::var_os("WAYLAND_DISPLAY").is_some()
+}
+
+/// Invokes the portal's color picker.
+///
+/// When the user picks a pixel, `on_pick` is called with its color. The
+/// portal reports sRGB in 0.0–1.0, so there are never unclamped
+/// components. If the user cancels, or there is no portal (or it lacks
+/// `PickColor`), nothing happens.
+///
+/// Like the macOS sampler, `on_pick` runs later on the UI thread, possibly
+/// after the picker is gone; see
+/// [`eyedropper_button`](crate::eyedropper::eyedropper_button).
+pub(crate) fn sample_color(on_pick: impl FnOnce(SolidColor, Option<(f64, f64, f64)>) + 'static) {
+    // A fresh root scope per pick, freed once the result is delivered
+    let scope = Scope::new();
+    let deliver = create_ext_action(scope, move |picked: Option<SolidColor>| {
+        if let Some(c) = picked {
+            on_pick(c, None);
+        }
+        scope.dispose();
+    });
+    std::thread::spawn(move || deliver(pollster::block_on(pick())));
+}
+
+/// Ask the portal for a color. `None` when cancelled or unavailable.
+async fn pick() -> Option<SolidColor> {
+    let color = Color::pick().send().await.ok()?.response().ok()?;
+    Some(SolidColor::from_rgba(
+        color.red(),
+        color.green(),
+        color.blue(),
+        1.0,
+    ))
+}
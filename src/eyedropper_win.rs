@@ -0,0 +1,151 @@
+//! Windows screen color sampler using GDI.
+//!
+//! Installs low-level mouse and keyboard hooks on a background thread and
+//! reads the pixel under the next left click with `GetPixel` on the screen
+//! DC. The hooks swallow that click (and an Esc or right click, which
+//! cancel) so nothing underneath reacts to it.
+//!
+//! `SetCapture` isn't enough here: it only captures for a window owned by
+//! the calling thread, and once no button is held it stops reporting input
+//! outside that window, so the first click on another app would go to that
+//! app instead. The system-wide hooks live only for one pick and are
+//! removed on every exit path by [`Hook`]'s `Drop`.
+
+use std::cell::Cell;
+
+use floem::ext_event::create_ext_action;
+use floem::reactive::Scope;
+use windows_sys::Win32::Foundation::{LPARAM, LRESULT, POINT, WPARAM};
+use windows_sys::Win32::Graphics::Gdi::{CLR_INVALID, GetDC, GetPixel, ReleaseDC};
+use windows_sys::Win32::System::LibraryLoader::GetModuleHandleW;
+use windows_sys::Win32::UI::Input::KeyboardAndMouse::VK_ESCAPE;
+use windows_sys::Win32::UI::WindowsAndMessaging::{
+    CallNextHookEx, GetMessageW, HC_ACTION, HHOOK, KBDLLHOOKSTRUCT, MSG, MSLLHOOKSTRUCT,
+    PostQuitMessage, SetWindowsHookExW, UnhookWindowsHookEx, WH_KEYBOARD_LL, WH_MOUSE_LL,
+    WM_KEYDOWN, WM_LBUTTONDOWN, WM_LBUTTONUP, WM_RBUTTONDOWN, WM_RBUTTONUP, WM_SYSKEYDOWN,
+};
+
+use crate::color::SolidColor;
+
+thread_local! {
+    /// Outcome of the running pick on the hook thread: `Some(None)` once
+    /// cancelled, `Some(Some(color))` once a pixel was clicked.
+    static OUTCOME: Cell<Option<Option<SolidColor>>> = const { Cell::new(None) };
+}
+
+/// Invokes the GDI screen color sampler.
+///
+/// When the user clicks a pixel, `on_pick` is called with its color. GDI
+/// reads 8-bit sRGB, so there are never unclamped components. If the user
+/// cancels (Esc or right click), or the hooks can't be installed, nothing
+/// happens.
+///
+/// Like the macOS sampler, `on_pick` runs later on the UI thread, possibly
+/// after the picker is gone; see
+/// [`eyedropper_button`](crate::eyedropper::eyedropper_button).
+pub(crate) fn sample_color(on_pick: impl FnOnce(SolidColor, Option<(f64, f64, f64)>) + 'static) {
+    // A fresh root scope per pick, freed once the result is delivered
+    let scope = Scope::new();
+    let deliver = create_ext_action(scope, move |picked: Option<SolidColor>| {
+        if let Some(c) = picked {
+            on_pick(c, None);
+        }
+        scope.dispose();
+    });
+    std::thread::spawn(move || deliver(pick()));
+}
+
+/// Run the hooks until a click or cancel. `None` when cancelled.
+fn pick() -> Option<SolidColor> {
+    OUTCOME.set(None);
+    // SAFETY: plain Win32 calls; the hooks are removed before returning and
+    // only touch this thread's `OUTCOME`.
+    unsafe {
+        let module = GetModuleHandleW(std::ptr::null());
+        let mouse = Hook(SetWindowsHookExW(WH_MOUSE_LL, Some(mouse_hook), module, 0));
+        let _keyboard = Hook(SetWindowsHookExW(
+            WH_KEYBOARD_LL,
+            Some(keyboard_hook),
+            module,
+            0,
+        ));
+        if !mouse.0.is_null() {
+            // Hooks run inside this loop; they post the quit message
+            let mut msg: MSG = std::mem::zeroed();
+            while GetMessageW(&mut msg, std::ptr::null_mut(), 0, 0) > 0 {}
+        }
+    }
+    OUTCOME.take().flatten()
+}
+
+/// An installed hook, removed when dropped. Null when installing failed.
+struct Hook(HHOOK);
+
+impl Drop for Hook {
+    fn drop(&mut self) {
+        if !self.0.is_null() {
+            // SAFETY: the handle came from `SetWindowsHookExW` on this thread
+            // and is unhooked only here.
+            unsafe { UnhookWindowsHookEx(self.0) };
+        }
+    }
+}
+
+/// Color of the screen pixel at `pt`, in virtual-screen coordinates.
+fn screen_pixel(pt: POINT) -> Option<SolidColor> {
+    // SAFETY: the screen DC is released right after the read.
+    let color = unsafe {
+        let dc = GetDC(std::ptr::null_mut());
+        if dc.is_null() {
+            return None;
+        }
+        let color = GetPixel(dc, pt.x, pt.y);
+        ReleaseDC(std::ptr::null_mut(), dc);
+        color
+    };
+    if color == CLR_INVALID {
+        return None;
+    }
+    // COLORREF is 0x00BBGGRR
+    let [r, g, b, _] = color.to_le_bytes();
+    Some(SolidColor::from_rgb(r, g, b))
+}
+
+/// Samples on left button down and cancels on right button down; both
+/// clicks are swallowed, and the pick ends on the matching button up.
+unsafe extern "system" fn mouse_hook(code: i32, wparam: WPARAM, lparam: LPARAM) -> LRESULT {
+    if code == HC_ACTION as i32 {
+        match wparam as u32 {
+            WM_LBUTTONDOWN => {
+                // SAFETY: for WH_MOUSE_LL, lparam points to an MSLLHOOKSTRUCT.
+                let info = unsafe { &*(lparam as *const MSLLHOOKSTRUCT) };
+                OUTCOME.set(Some(screen_pixel(info.pt)));
+                return 1;
+            }
+            WM_RBUTTONDOWN => {
+                OUTCOME.set(Some(None));
+                return 1;
+            }
+            WM_LBUTTONUP | WM_RBUTTONUP if OUTCOME.get().is_some() => {
+                unsafe { PostQuitMessage(0) };
+                return 1;
+            }
+            _ => {}
+        }
+    }
+    unsafe { CallNextHookEx(std::ptr::null_mut(), code, wparam, lparam) }
+}
+
+/// Cancels on Esc, which is swallowed.
+unsafe extern "system" fn keyboard_hook(code: i32, wparam: WPARAM, lparam: LPARAM) -> LRESULT {
+    if code == HC_ACTION as i32 && matches!(wparam as u32, WM_KEYDOWN | WM_SYSKEYDOWN) {
+        // SAFETY: for WH_KEYBOARD_LL, lparam points to a KBDLLHOOKSTRUCT.
+        let info = unsafe { &*(lparam as *const KBDLLHOOKSTRUCT) };
+        if info.vkCode == VK_ESCAPE as u32 {
+            OUTCOME.set(Some(None));
+            unsafe { PostQuitMessage(0) };
+            return 1;
+        }
+    }
+    unsafe { CallNextHookEx(std::ptr::null_mut(), code, wparam, lparam) }
+}
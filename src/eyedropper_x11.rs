@@ -0,0 +1,180 @@
+//! X11 screen color sampler.
+//!
+//! Grabs the pointer (with a crosshair cursor) and the keyboard on the root
+//! window, then reads the pixel under the next click with `GetImage`. Esc
+//! or any other button than the left one cancels. Runs on its own X
+//! connection in a background thread, so the UI keeps drawing meanwhile.
+
+use std::error::Error;
+
+use floem::ext_event::create_ext_action;
+use floem::reactive::Scope;
+use x11rb::connection::Connection;
+use x11rb::protocol::Event;
+use x11rb::protocol::xproto::{
+    ConnectionExt as _, EventMask, GetImageReply, GrabMode, GrabStatus, ImageFormat, ImageOrder,
+    Screen, Setup,
+};
+
+use crate::color::SolidColor;
+
+/// `XC_crosshair` in the standard X cursor font; its mask is the next glyph.
+const CROSSHAIR_GLYPH: u16 = 34;
+
+/// `XK_Escape`.
+const ESCAPE_KEYSYM: u32 = 0xff1b;
+
+/// Primary (left) mouse button.
+const LEFT_BUTTON: u8 = 1;
+
+/// Invokes the X11 screen color sampler.
+///
+/// When the user clicks a pixel, `on_pick` is called with its color. X11
+/// root windows are sRGB, so there are never unclamped components. If the
+/// user cancels (Esc or another button), or the pointer can't be grabbed,
+/// nothing happens.
+///
+/// Like the macOS sampler, `on_pick` runs later on the UI thread, possibly
+/// after the picker is gone; see
+/// [`eyedropper_button`](crate::eyedropper::eyedropper_button).
+pub(crate) fn sample_color(on_pick: impl FnOnce(SolidColor, Option<(f64, f64, f64)>) + 'static) {
+    // A fresh root scope per pick, freed once the result is delivered
+    let scope = Scope::new();
+    let deliver = create_ext_action(scope, move |picked: Option<SolidColor>| {
+        if let Some(c) = picked {
+            on_pick(c, None);
+        }
+        scope.dispose();
+    });
+    std::thread::spawn(move || deliver(pick().ok().flatten()));
+}
+
+/// Run the grab and wait for a click. `Ok(None)` when cancelled.
+fn pick() -> Result<Option<SolidColor>, Box<dyn Error>> {
+    let (conn, screen_num) = x11rb::connect(None)?;
+    let setup = conn.setup();
+    let screen = &setup.roots[screen_num];
+    let root = screen.root;
+
+    let font = conn.generate_id()?;
+    conn.open_font(font, b"cursor")?;
+    let cursor = conn.generate_id()?;
+    conn.create_glyph_cursor(
+        cursor,
+        font,
+        font,
+        CROSSHAIR_GLYPH,
+        CROSSHAIR_GLYPH + 1,
+        0,
+        0,
+        0,
+        0xffff,
+        0xffff,
+        0xffff,
+    )?;
+
+    let pointer = conn
+        .grab_pointer(
+            false,
+            root,
+            EventMask::BUTTON_PRESS,
+            GrabMode::ASYNC,
+            GrabMode::ASYNC,
+            x11rb::NONE,
+            cursor,
+            x11rb::CURRENT_TIME,
+        )?
+        .reply()?;
+    if pointer.status != GrabStatus::SUCCESS {
+        return Ok(None);
+    }
+    // Without the keyboard Esc can't cancel, but a click still samples
+    let _ = conn
+        .grab_keyboard(
+            false,
+            root,
+            x11rb::CURRENT_TIME,
+            GrabMode::ASYNC,
+            GrabMode::ASYNC,
+        )?
+        .reply();
+    let escape = escape_keycodes(&conn, setup)?;
+
+    let picked = loop {
+        match conn.wait_for_event()? {
+            Event::ButtonPress(ev) if ev.detail == LEFT_BUTTON => {
+                let image = conn
+                    .get_image(ImageFormat::Z_PIXMAP, root, ev.root_x, ev.root_y, 1, 1, !0)?
+                    .reply()?;
+                break pixel_color(setup, screen, &image);
+            }
+            Event::ButtonPress(_) => break None,
+            Event::KeyPress(ev) if escape.contains(&ev.detail) => break None,
+            _ => {}
+        }
+    };
+
+    conn.ungrab_keyboard(x11rb::CURRENT_TIME)?;
+    conn.ungrab_pointer(x11rb::CURRENT_TIME)?;
+    conn.free_cursor(cursor)?;
+    conn.close_font(font)?;
+    conn.flush()?;
+    Ok(picked)
+}
+
+/// Keycodes that produce Escape in the current keyboard mapping.
+fn escape_keycodes(conn: &impl Connection, setup: &Setup) -> Result<Vec<u8>, Box<dyn Error>> {
+    let count = setup.max_keycode - setup.min_keycode + 1;
+    let mapping = conn
+        .get_keyboard_mapping(setup.min_keycode, count)?
+        .reply()?;
+    let per_keycode = mapping.keysyms_per_keycode.max(1) as usize;
+    Ok(mapping
+        .keysyms
+        .chunks(per_keycode)
+        .zip(setup.min_keycode..=setup.max_keycode)
+        .filter(|(syms, _)| syms.contains(&ESCAPE_KEYSYM))
+        .map(|(_, keycode)| keycode)
+        .collect())
+}
+
+/// Decode the single pixel of a `GetImage` reply with the channel masks of
+/// its visual. `None` for visuals without direct RGB masks (e.g. 8-bit
+/// palettes) or pixel sizes other than 24 and 32 bits.
+fn pixel_color(setup: &Setup, screen: &Screen, image: &GetImageReply) -> Option<SolidColor> {
+    let visual = screen
+        .allowed_depths
+        .iter()
+        .flat_map(|d| &d.visuals)
+        .find(|v| v.visual_id == image.visual)?;
+    let bits = setup
+        .pixmap_formats
+        .iter()
+        .find(|f| f.depth == image.depth)?
+        .bits_per_pixel;
+    let bytes = match bits {
+        24 | 32 => image.data.get(..bits as usize / 8)?,
+        _ => return None,
+    };
+    let pixel = if setup.image_byte_order == ImageOrder::LSB_FIRST {
+        bytes
+            .iter()
+            .rev()
+            .fold(0u32, |acc, &b| (acc << 8) | b as u32)
+    } else {
+        bytes.iter().fold(0u32, |acc, &b| (acc << 8) | b as u32)
+    };
+    let channel = |mask: u32| {
+        if mask == 0 {
+            return None;
+        }
+        let shift = mask.trailing_zeros();
+        Some(((pixel & mask) >> shift) as f64 / (mask >> shift) as f64)
+    };
+    Some(SolidColor::from_rgba(
+        channel(visual.red_mask)?,
+        channel(visual.green_mask)?,
+        channel(visual.blue_mask)?,
+        1.0,
+    ))
+}
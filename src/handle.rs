@@ -0,0 +1,121 @@
+//! Handle for observing a picker from the embedding application.
+
+use floem::reactive::{Memo, SignalGet, SignalUpdate, batch};
+
+use crate::color_editor::EditorSignals;
+use crate::config::GroundTruth;
+
+/// Observes a picker created with [`solid_picker_with`](crate::solid_picker_with).
+#[derive(Clone, Copy)]
+pub struct PickerHandle {
+    pub(crate) is_valid: Memo<bool>,
+    pub(crate) signals: EditorSignals,
+    pub(crate) ground_truth: GroundTruth,
+}
+
+/// The picker's exact HSB decomposition, for undo or save/restore.
+///
+/// Unlike the bound [`SolidColor`](crate::SolidColor), this keeps the hue of
+/// grays and low-saturation colors, which RGB can't represent.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PickerState {
+    /// Hue, 0.0–1.0.
+    pub hue: f64,
+    /// HSB saturation, 0.0–1.0.
+    pub saturation: f64,
+    /// HSB brightness, 0.0–1.0.
+    pub brightness: f64,
+    /// Alpha, 0.0–1.0.
+    pub alpha: f64,
+    /// Ground truth of the picker the state was taken from.
+    pub ground_truth: GroundTruth,
+}
+
+impl PickerHandle {
+    /// `false` while any text field holds uncommitted text that doesn't
+    /// parse (e.g. a half-typed hex code), `true` once every field is valid.
+    /// Useful for blocking form submission.
+    pub fn is_valid(&self) -> Memo<bool> {
+        self.is_valid
+    }
+
+    /// Capture the picker's current state.
+    pub fn snapshot(&self) -> PickerState {
+        let EditorSignals { h, s, b, a } = self.signals;
+        PickerState {
+            hue: h.get_untracked(),
+            saturation: s.get_untracked(),
+            brightness: b.get_untracked(),
+            alpha: a.get_untracked(),
+            ground_truth: self.ground_truth,
+        }
+    }
+
+    /// Restore a state from [`snapshot`](Self::snapshot). The bound color and
+    /// all rows update to match.
+    pub fn restore(&self, state: PickerState) {
+        let EditorSignals { h, s, b, a } = self.signals;
+        batch(|| {
+            h.set(state.hue.rem_euclid(1.0));
+            s.set(state.saturation.clamp(0.0, 1.0));
+            b.set(state.brightness.clamp(0.0, 1.0));
+            a.set(state.alpha.clamp(0.0, 1.0));
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use floem::reactive::create_memo;
+
+    fn handle() -> PickerHandle {
+        PickerHandle {
+            is_valid: create_memo(|_| true),
+            signals: EditorSignals::new(),
+            ground_truth: GroundTruth::Hsl,
+        }
+    }
+
+    #[test]
+    fn snapshot_restore_keeps_the_hue_of_grays() {
+        let handle = handle();
+        let gray = PickerState {
+            hue: 0.6,
+            saturation: 0.0,
+            brightness: 0.5,
+            alpha: 0.75,
+            ground_truth: GroundTruth::Hsl,
+        };
+        handle.restore(gray);
+        assert_eq!(handle.snapshot(), gray);
+
+        handle.restore(PickerState {
+            hue: 0.1,
+            saturation: 1.0,
+            ..gray
+        });
+        handle.restore(gray);
+        assert_eq!(handle.snapshot().hue, 0.6);
+    }
+
+    #[test]
+    fn restore_wraps_hue_and_clamps_channels() {
+        let handle = handle();
+        handle.restore(PickerState {
+            hue: 1.25,
+            saturation: 1.5,
+            brightness: -0.5,
+            alpha: 2.0,
+            ground_truth: GroundTruth::Hsb,
+        });
+        let state = handle.snapshot();
+        assert_eq!(
+            (state.hue, state.saturation, state.brightness, state.alpha),
+            (0.25, 1.0, 0.0, 1.0)
+        );
+        // The handle reports its own picker's ground truth
+        assert_eq!(state.ground_truth, GroundTruth::Hsl);
+    }
+}
@@ -0,0 +1,97 @@
+//! Hue × saturation density of an image's pixels, overlaid on the wheel to
+//! show where a reference image's colors cluster.
+
+use std::f64::consts::TAU;
+
+use floem::context::PaintCx;
+use floem::kurbo::{Circle, Point, Vec2};
+use floem::peniko::Color;
+
+use floem_renderer::Renderer;
+
+use crate::color::SolidColor;
+use crate::config::WheelMode;
+
+/// Bins around the wheel (10° each).
+const HUE_BINS: usize = 36;
+
+/// Bins from the center to the rim.
+const SAT_BINS: usize = 8;
+
+/// At most this many pixels are sampled; larger images are strided.
+const MAX_SAMPLES: u64 = 1 << 16;
+
+/// Pixels with less alpha or saturation carry no usable hue and are skipped.
+const MIN_ALPHA: u8 = 128;
+const MIN_SATURATION: f64 = 0.05;
+
+/// Strongest overlay opacity, for the fullest bin.
+const MAX_OPACITY: f64 = 0.55;
+
+/// Pixel counts binned by hue and saturation, normalized so the fullest
+/// bin is 1.0.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Heatmap {
+    /// `HUE_BINS * SAT_BINS` densities, hue-major.
+    bins: Vec<f64>,
+}
+
+impl Heatmap {
+    /// Bin `img`'s pixels by hue and by saturation in the wheel's model.
+    pub(crate) fn from_image(img: &image::RgbaImage, mode: WheelMode) -> Self {
+        let mut bins = vec![0.0; HUE_BINS * SAT_BINS];
+        let total = img.width() as u64 * img.height() as u64;
+        let stride = total.div_ceil(MAX_SAMPLES).max(1) as usize;
+        for px in img.pixels().step_by(stride) {
+            if px[3] < MIN_ALPHA {
+                continue;
+            }
+            let c = SolidColor::from_rgb(px[0], px[1], px[2]);
+            let (h, s) = match mode {
+                WheelMode::Hsb => {
+                    let (h, s, _) = c.to_hsb();
+                    (h, s)
+                }
+                WheelMode::Hsl => {
+                    let (h, s, _) = c.to_hsl();
+                    (h, s)
+                }
+            };
+            if s < MIN_SATURATION {
+                continue;
+            }
+            let hi = ((h * HUE_BINS as f64) as usize).min(HUE_BINS - 1);
+            let si = ((s * SAT_BINS as f64) as usize).min(SAT_BINS - 1);
+            bins[hi * SAT_BINS + si] += 1.0;
+        }
+        let max = bins.iter().copied().fold(0.0, f64::max);
+        if max > 0.0 {
+            bins.iter_mut().for_each(|d| *d /= max);
+        }
+        Self { bins }
+    }
+
+    /// Paint one translucent dot of `ink` per occupied bin over a wheel of
+    /// `radius` around `center`, more opaque and larger where pixels are
+    /// denser.
+    pub(crate) fn paint(&self, cx: &mut PaintCx, center: Point, radius: f64, ink: Color) {
+        let ring = radius / SAT_BINS as f64;
+        for (i, &density) in self.bins.iter().enumerate() {
+            if density <= 0.0 {
+                continue;
+            }
+            let (hi, si) = (i / SAT_BINS, i % SAT_BINS);
+            let angle = (hi as f64 + 0.5) / HUE_BINS as f64 * TAU;
+            let r = (si as f64 + 0.5) * ring;
+            let pos = center + Vec2::new(angle.cos() * r, angle.sin() * r);
+            // Keep dots within their bin near the center, where bins are narrow
+            let arc = r * TAU / HUE_BINS as f64;
+            let dot = 0.5 * ring.min(arc) * (0.4 + 0.6 * density.sqrt());
+            cx.fill(
+                &Circle::new(pos, dot),
+                ink.multiply_alpha((MAX_OPACITY * density.sqrt()) as f32),
+                0.0,
+            );
+        }
+    }
+}
@@ -0,0 +1,91 @@
+//! Focus highlight linking numeric fields to the graphical controls.
+//!
+//! While a field has focus, every wheel or slider that doesn't edit the
+//! field's value is veiled, so the control that does stands out.
+
+use floem::prelude::*;
+use floem::reactive::{RwSignal, SignalGet, SignalUpdate};
+
+use crate::constants;
+
+/// Value edited by a numeric field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Dimension {
+    Hue,
+    Saturation,
+    Brightness,
+    Lightness,
+    #[cfg_attr(not(feature = "alpha"), allow(dead_code))]
+    Alpha,
+}
+
+/// The dimension whose field has focus, if any.
+#[derive(Clone, Copy)]
+pub(crate) struct Highlight(RwSignal<Option<Dimension>>);
+
+impl Highlight {
+    pub(crate) fn new() -> Self {
+        Self(RwSignal::new(None))
+    }
+
+    /// Focus hooks for a field editing `dim`.
+    pub(crate) fn field(self, dim: Dimension) -> FieldFocus {
+        FieldFocus {
+            highlight: self,
+            dim,
+        }
+    }
+}
+
+/// Reports a field's focus changes to its [`Highlight`].
+#[derive(Clone, Copy)]
+pub(crate) struct FieldFocus {
+    highlight: Highlight,
+    dim: Dimension,
+}
+
+impl FieldFocus {
+    pub(crate) fn gained(self) {
+        self.highlight.0.set(Some(self.dim));
+    }
+
+    /// Clears the highlight unless another field has already taken it.
+    pub(crate) fn lost(self) {
+        if self.highlight.0.get_untracked() == Some(self.dim) {
+            self.highlight.0.set(None);
+        }
+    }
+}
+
+/// Veil over dimmed controls, in the panel's gray.
+const VEIL: Color = Color::rgba8(
+    constants::PANEL_GRAY,
+    constants::PANEL_GRAY,
+    constants::PANEL_GRAY,
+    170,
+);
+
+/// `view` with a veil shown while a field outside `dims` has focus. With no
+/// `highlight`, returns `view` unwrapped.
+pub(crate) fn dim_unless(
+    view: impl IntoView + 'static,
+    highlight: Option<Highlight>,
+    dims: &'static [Dimension],
+) -> floem::AnyView {
+    let Some(highlight) = highlight else {
+        return view.into_any();
+    };
+    let dimmed = move || matches!(highlight.0.get(), Some(d) if !dims.contains(&d));
+    stack((
+        view,
+        empty().style(move |s| {
+            s.absolute()
+                .inset(0.0)
+                .border_radius(constants::RADIUS)
+                .background(VEIL)
+                .apply_if(!dimmed(), |s| s.hide())
+        }),
+    ))
+    .style(|s| s.flex_col())
+    .into_any()
+}
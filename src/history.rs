@@ -0,0 +1,89 @@
+//! Recently used colors.
+//!
+//! The history lives in a caller-provided signal, so several pickers can
+//! share it. A color is recorded once it has been left unchanged for
+//! [`SETTLE_DELAY`], which skips the intermediate colors of a drag or of
+//! typing.
+
+use std::cell::Cell;
+use std::rc::Rc;
+use std::time::Duration;
+
+use floem::reactive::{RwSignal, SignalGet, SignalUpdate, create_effect};
+
+use crate::color::SolidColor;
+
+/// How long a color must stay unchanged before it is recorded.
+const SETTLE_DELAY: Duration = Duration::from_millis(500);
+
+/// Move `color` to the front of `history`, removing an earlier copy (by
+/// 8-bit hex) and trimming to `max_len` entries.
+pub(crate) fn push(history: &mut Vec<SolidColor>, color: SolidColor, max_len: usize) {
+    let hex = color.to_hex();
+    history.retain(|c| c.to_hex() != hex);
+    history.insert(0, color);
+    history.truncate(max_len);
+}
+
+/// Record settled values of `color` into `history`.
+///
+/// The color the picker opens with is not recorded.
+pub(crate) fn record(
+    color: RwSignal<SolidColor>,
+    history: RwSignal<Vec<SolidColor>>,
+    max_len: usize,
+) {
+    let generation = Rc::new(Cell::new(0u64));
+    create_effect(move |prev: Option<()>| {
+        let c = color.get();
+        if prev.is_none() {
+            return;
+        }
+        let current = generation.get() + 1;
+        generation.set(current);
+        let generation = generation.clone();
+        floem::action::exec_after(SETTLE_DELAY, move |_| {
+            if generation.get() == current {
+                history.update(|h| push(h, c, max_len));
+            }
+        });
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hex(c: &SolidColor) -> String {
+        c.to_hex()
+    }
+
+    #[test]
+    fn push_moves_repeats_to_the_front_and_trims() {
+        let mut h = Vec::new();
+        for c in ["FF0000", "00FF00", "0000FF", "FF0000"] {
+            push(&mut h, SolidColor::from_hex(c).unwrap(), 3);
+        }
+        assert_eq!(
+            h.iter().map(hex).collect::<Vec<_>>(),
+            ["FF0000", "0000FF", "00FF00"]
+        );
+        push(&mut h, SolidColor::from_hex("FFFFFF").unwrap(), 3);
+        assert_eq!(
+            h.iter().map(hex).collect::<Vec<_>>(),
+            ["FFFFFF", "FF0000", "0000FF"]
+        );
+    }
+
+    #[test]
+    fn pickers_sharing_a_signal_see_each_others_colors() {
+        let shared = RwSignal::new(Vec::new());
+        // Two pickers writing through the same signal
+        let (first, second) = (shared, shared);
+        first.update(|h| push(h, SolidColor::from_rgb(0x3B, 0x82, 0xF6), 12));
+        second.update(|h| push(h, SolidColor::from_rgb(0xFA, 0xCC, 0x15), 12));
+        let seen: Vec<_> = first.get().iter().map(hex).collect();
+        assert_eq!(seen, ["FACC15", "3B82F6"]);
+        assert_eq!(second.get().len(), 2);
+    }
+}
@@ -0,0 +1,98 @@
+//! Lucide icon font loading, with text fallbacks.
+//!
+//! Icons are glyphs of the bundled Lucide font. If the font doesn't end up
+//! in the font database (e.g. font loading is unsupported on the platform),
+//! icons are drawn as short text labels instead of missing-glyph boxes.
+
+use std::sync::Once;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use floem::style::Style;
+use floem::text::FONT_SYSTEM;
+use lucide_icons::Icon;
+
+static LOAD_LUCIDE_FONT: Once = Once::new();
+
+/// Whether the Lucide font was found in the font database after loading.
+static LUCIDE_AVAILABLE: AtomicBool = AtomicBool::new(false);
+
+/// Family name of the bundled font.
+const FAMILY: &str = "lucide";
+
+/// Load the Lucide font into the font database, once.
+pub(crate) fn load() {
+    LOAD_LUCIDE_FONT.call_once(|| {
+        let mut font_system = FONT_SYSTEM.lock();
+        let db = font_system.db_mut();
+        // Loading reports no errors; look the family up afterwards instead
+        db.load_font_data(lucide_icons::LUCIDE_FONT_BYTES.to_vec());
+        let found = db.faces().any(|face| {
+            face.families
+                .iter()
+                .any(|(name, _)| name.eq_ignore_ascii_case(FAMILY))
+        });
+        LUCIDE_AVAILABLE.store(found, Ordering::Relaxed);
+    });
+}
+
+/// Whether icons are drawn with the Lucide font.
+pub(crate) fn available() -> bool {
+    LUCIDE_AVAILABLE.load(Ordering::Relaxed)
+}
+
+/// The text to show for `icon`: its glyph, or a fallback label when the
+/// font is unavailable.
+pub(crate) fn text(icon: Icon) -> String {
+    text_with(icon, available())
+}
+
+/// [`text`] with the font's availability given.
+fn text_with(icon: Icon, available: bool) -> String {
+    if available {
+        return icon.unicode().to_string();
+    }
+    match icon {
+        Icon::Copy => "Copy",
+        Icon::Pipette => "Pick",
+        Icon::Image => "PNG",
+        Icon::Braces => "{}",
+        Icon::ArrowLeftRight => "⇄",
+        Icon::Magnet => "Snap",
+        Icon::Palette => "Pal",
+        Icon::Contrast => "◐",
+        Icon::Sun => "☀",
+        Icon::Snowflake => "❄",
+        _ => "?",
+    }
+    .to_string()
+}
+
+/// `s` with the Lucide font family set, if the font is available.
+pub(crate) fn font(s: Style) -> Style {
+    if available() {
+        s.font_family(FAMILY.to_string())
+    } else {
+        s
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn missing_font_falls_back_to_text() {
+        assert_eq!(text_with(Icon::Copy, false), "Copy");
+        assert_eq!(text_with(Icon::Pipette, false), "Pick");
+        assert_eq!(text_with(Icon::Contrast, false), "◐");
+    }
+
+    #[test]
+    fn loaded_font_uses_glyphs() {
+        assert_eq!(
+            text_with(Icon::Copy, true),
+            Icon::Copy.unicode().to_string()
+        );
+        assert_ne!(text_with(Icon::Copy, true), "Copy");
+    }
+}
@@ -1,48 +1,238 @@
 //! Numeric input components for color channel editing.
 
+use std::cell::Cell;
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+
 use floem::event::EventPropagation;
 use floem::prelude::*;
 use floem::reactive::{RwSignal, SignalGet, SignalUpdate, create_effect};
 
+use crate::clipboard::SharedClipboard;
+use crate::color::SolidColor;
+#[cfg(feature = "alpha")]
+use crate::config::AlphaDisplay;
+use crate::config::{StepSize, WindowBlurCommit};
 use crate::constants;
+use crate::highlight::FieldFocus;
+use crate::icon_font;
+use crate::named;
+
+/// Count of fields holding uncommitted text that doesn't parse.
+///
+/// Each field registers with [`track`](Self::track); the picker is valid
+/// when no field is invalid.
+#[derive(Clone, Copy)]
+pub(crate) struct Validity(RwSignal<u32>);
+
+impl Validity {
+    pub(crate) fn new() -> Self {
+        Self(RwSignal::new(0))
+    }
+
+    /// Reactive: true when every tracked field holds valid text.
+    pub(crate) fn is_valid(&self) -> bool {
+        self.0.get() == 0
+    }
+
+    /// Track a field whose validity is given by the reactive `valid`.
+    pub(crate) fn track(&self, valid: impl Fn() -> bool + 'static) {
+        let count = self.0;
+        create_effect(move |prev: Option<bool>| {
+            let now = valid();
+            match (prev.unwrap_or(true), now) {
+                (true, false) => count.update(|n| *n += 1),
+                (false, true) => count.update(|n| *n -= 1),
+                _ => {}
+            }
+            now
+        });
+    }
+}
+
+/// Whether the window is active, so fields can tell a window switch from
+/// moving to another field when they lose focus.
+#[derive(Clone, Copy)]
+pub(crate) struct WindowBlur {
+    active: RwSignal<bool>,
+    policy: WindowBlurCommit,
+}
+
+impl WindowBlur {
+    pub(crate) fn new(policy: WindowBlurCommit) -> Self {
+        Self {
+            active: RwSignal::new(true),
+            policy,
+        }
+    }
+
+    /// Record a window focus change.
+    pub(crate) fn set_active(&self, active: bool) {
+        self.active.set(active);
+    }
+
+    /// Whether a field losing focus should commit text that is `valid`.
+    fn commits(&self, valid: bool) -> bool {
+        self.active.get_untracked() || self.policy.commits(valid)
+    }
+}
 
 /// A numeric input that maps a normalized 0.0–1.0 signal to a display range.
 ///
 /// For example, hue maps 0.0–1.0 → 0–360, saturation maps 0.0–1.0 → 0–100.
+/// Up/Down arrows nudge the value by `step` display units (Shift for large).
+/// With `live`, every keystroke that leaves a valid in-range number updates
+/// the signal; otherwise the value commits on Enter or blur. With `scrub`,
+/// dragging the label left or right also changes the value.
+#[allow(clippy::too_many_arguments)]
 pub(crate) fn number_input(
     lbl: &'static str,
     signal: RwSignal<f64>,
     max_display: f64,
+    step: StepSize,
+    validity: Validity,
+    blur: WindowBlur,
+    live: bool,
+    scrub: bool,
+    focus: Option<FieldFocus>,
+) -> impl IntoView {
+    number_input_with(
+        lbl,
+        signal,
+        max_display,
+        step,
+        validity,
+        blur,
+        live,
+        scrub,
+        focus,
+        |v| v.to_string(),
+        |s| s.parse::<f64>().ok(),
+    )
+}
+
+/// A two-digit hex field for one 0–255 channel (e.g. `RR`).
+///
+/// Out-of-range or non-hex text resets to the current value.
+pub(crate) fn hex_byte_input(
+    lbl: &'static str,
+    signal: RwSignal<f64>,
+    step: StepSize,
+    validity: Validity,
+    blur: WindowBlur,
+    live: bool,
 ) -> impl IntoView {
-    let text = RwSignal::new(format_value(signal.get_untracked(), max_display));
+    number_input_with(
+        lbl,
+        signal,
+        255.0,
+        step,
+        validity,
+        blur,
+        live,
+        false,
+        None,
+        |v| format!("{:02X}", v),
+        parse_hex_byte,
+    )
+}
+
+/// Parse a hex byte field (`"3B"`, case-insensitive) to its 0–255 value.
+fn parse_hex_byte(s: &str) -> Option<f64> {
+    u8::from_str_radix(s.trim(), 16).ok().map(f64::from)
+}
+
+/// Pointer travel, in logical pixels, per step when scrubbing a label.
+const SCRUB_PIXELS: f64 = 4.0;
+
+/// Display value after scrubbing `dx` pixels from `start`: one `step` per
+/// [`SCRUB_PIXELS`] of travel, clamped to 0–`max_display`.
+fn scrub_value(start: f64, dx: f64, step: f64, max_display: f64) -> f64 {
+    (start + (dx / SCRUB_PIXELS).trunc() * step).clamp(0.0, max_display)
+}
+
+/// Keep `text` showing `signal` as a rounded 0–`max_display` value and,
+/// with `live`, write each valid in-range keystroke back to `signal`.
+fn link_text(
+    text: RwSignal<String>,
+    signal: RwSignal<f64>,
+    max_display: f64,
+    live: bool,
+    format: fn(i64) -> String,
+    parse: fn(&str) -> Option<f64>,
+) {
+    let display_of = move |normalized: f64| format((normalized * max_display).round() as i64);
 
     // Signal → text (external updates)
     create_effect(move |_| {
         let val = signal.get();
-        let display = (val * max_display).round();
         let current = text.get_untracked();
-        let expected = format!("{}", display as i64);
+        // In live mode, leave text that already means this value as typed
+        // (e.g. "05") so the field isn't rewritten under the cursor
+        if live
+            && parse(&current).map(|n| n.round() as i64) == Some((val * max_display).round() as i64)
+        {
+            return;
+        }
+        let expected = display_of(val);
         if current != expected {
             text.set(expected);
         }
     });
 
+    // Text → signal on each keystroke (live mode). Partial or out-of-range
+    // text ("", "-", "400" for hue) waits for commit instead.
+    if live {
+        create_effect(move |_| {
+            let raw = text.get();
+            if let Some(num) = parse(&raw)
+                && (0.0..=max_display).contains(&num)
+                && num.round() as i64 != (signal.get_untracked() * max_display).round() as i64
+            {
+                signal.set(num / max_display);
+            }
+        });
+    }
+}
+
+/// [`number_input`] with a custom display formatter and parser for the
+/// rounded display value.
+#[allow(clippy::too_many_arguments)]
+fn number_input_with(
+    lbl: &'static str,
+    signal: RwSignal<f64>,
+    max_display: f64,
+    step: StepSize,
+    validity: Validity,
+    blur: WindowBlur,
+    live: bool,
+    scrub: bool,
+    focus: Option<FieldFocus>,
+    format: fn(i64) -> String,
+    parse: fn(&str) -> Option<f64>,
+) -> impl IntoView {
+    let display_of = move |normalized: f64| format((normalized * max_display).round() as i64);
+    let text = RwSignal::new(display_of(signal.get_untracked()));
+    validity.track(move || parse(&text.get()).is_some());
+
+    link_text(text, signal, max_display, live, format, parse);
+
     let on_commit = move || {
         let raw = text.get_untracked();
-        if let Ok(num) = raw.parse::<f64>() {
+        if let Some(num) = parse(&raw) {
             let clamped = num.clamp(0.0, max_display);
             let new_display = clamped.round() as i64;
             let old_display = (signal.get_untracked() * max_display).round() as i64;
             if new_display != old_display {
                 signal.set(clamped / max_display);
             }
-            let formatted = format!("{}", new_display);
+            let formatted = format(new_display);
             if raw != formatted {
                 text.set(formatted);
             }
         } else {
             // Reset to current signal value
-            let formatted = format!("{}", (signal.get_untracked() * max_display).round() as i64);
+            let formatted = display_of(signal.get_untracked());
             if raw != formatted {
                 text.set(formatted);
             }
@@ -51,6 +241,18 @@ pub(crate) fn number_input(
 
     let on_commit_clone = on_commit;
 
+    // Arrow-key nudge by `delta` display units
+    let step_by = move |delta: f64| {
+        let current = (signal.get_untracked() * max_display).round();
+        let next = (current + delta).clamp(0.0, max_display);
+        signal.set(next / max_display);
+    };
+
+    // Scrub origin: pointer x and display value when the label was pressed
+    let scrub_from = RwSignal::new(None::<(f64, f64)>);
+    let caption = label(move || lbl);
+    let caption_id = caption.id();
+
     v_stack((
         text_input(text)
             .style(|s| {
@@ -74,37 +276,120 @@ pub(crate) fn number_input(
                             .outline_color(Color::rgba8(179, 215, 255, 128))
                     })
             })
+            .on_event_cont(floem::event::EventListener::FocusGained, move |_| {
+                if let Some(focus) = focus {
+                    focus.gained();
+                }
+            })
             .on_event_stop(floem::event::EventListener::FocusLost, move |_| {
-                on_commit();
+                if let Some(focus) = focus {
+                    focus.lost();
+                }
+                if blur.commits(parse(&text.get_untracked()).is_some()) {
+                    on_commit();
+                }
             })
             .on_event(floem::event::EventListener::KeyDown, move |e| {
-                if let floem::event::Event::KeyDown(ke) = e
-                    && ke.key.logical_key
-                        == floem::keyboard::Key::Named(floem::keyboard::NamedKey::Enter)
+                if let floem::event::Event::KeyDown(ke) = e {
+                    let large = ke.modifiers.contains(floem::keyboard::Modifiers::SHIFT);
+                    match &ke.key.logical_key {
+                        floem::keyboard::Key::Named(floem::keyboard::NamedKey::Enter) => {
+                            on_commit_clone();
+                            return EventPropagation::Stop;
+                        }
+                        floem::keyboard::Key::Named(floem::keyboard::NamedKey::ArrowUp) => {
+                            step_by(step.get(large));
+                            return EventPropagation::Stop;
+                        }
+                        floem::keyboard::Key::Named(floem::keyboard::NamedKey::ArrowDown) => {
+                            step_by(-step.get(large));
+                            return EventPropagation::Stop;
+                        }
+                        _ => {}
+                    }
+                }
+                EventPropagation::Continue
+            }),
+        caption
+            .style(move |s| {
+                s.font_size(constants::LABEL_FONT)
+                    .margin_top(2.0)
+                    .color(Color::rgb8(84, 84, 84))
+                    .justify_content(Some(floem::taffy::AlignContent::Center))
+                    .apply_if(scrub, |s| s.cursor(floem::style::CursorStyle::ColResize))
+            })
+            .on_event(floem::event::EventListener::PointerDown, move |e| {
+                if let floem::event::Event::PointerDown(pe) = e
+                    && scrub
+                {
+                    caption_id.request_active();
+                    let start = (signal.get_untracked() * max_display).round();
+                    scrub_from.set(Some((pe.pos.x, start)));
+                    return EventPropagation::Stop;
+                }
+                EventPropagation::Continue
+            })
+            .on_event(floem::event::EventListener::PointerMove, move |e| {
+                if let floem::event::Event::PointerMove(pe) = e
+                    && let Some((x0, start)) = scrub_from.get_untracked()
                 {
-                    on_commit_clone();
+                    let large = pe.modifiers.contains(floem::keyboard::Modifiers::SHIFT);
+                    let next = scrub_value(start, pe.pos.x - x0, step.get(large), max_display);
+                    if next != (signal.get_untracked() * max_display).round() {
+                        signal.set(next / max_display);
+                    }
                     return EventPropagation::Stop;
                 }
                 EventPropagation::Continue
+            })
+            .on_event_cont(floem::event::EventListener::PointerUp, move |_| {
+                scrub_from.set(None);
             }),
-        label(move || lbl).style(|s| {
-            s.font_size(constants::LABEL_FONT)
-                .margin_top(2.0)
-                .color(Color::rgb8(84, 84, 84))
-                .justify_content(Some(floem::taffy::AlignContent::Center))
-        }),
     ))
     .style(|s| s.items_center().gap(1.0))
 }
 
-fn format_value(normalized: f64, max: f64) -> String {
-    let display = (normalized * max).round() as i64;
-    format!("{}", display)
+/// Color name offered as a completion for hex field `text`, if any.
+///
+/// Text made only of hex digits is taken as a hex code in progress, and a
+/// single letter is too short to be worth completing.
+fn name_completion(text: &str) -> Option<&'static str> {
+    let t = text.trim().trim_start_matches('#');
+    if t.len() < 2 || t.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    named::complete(t)
+}
+
+/// Hex of the color in hex field `text` with its HSL lightness raised by
+/// `delta` (lowered if negative), or `None` if `text` isn't a valid color.
+fn nudged_hex(text: &str, delta: f64) -> Option<String> {
+    SolidColor::from_css(text).map(|c| c.lighten(delta).to_hex())
 }
 
 /// A hex input field that syncs bidirectionally with an RwSignal<String>.
-pub(crate) fn hex_input(hex_signal: RwSignal<String>) -> impl IntoView {
+///
+/// Up/Down arrows lighten/darken the current color by `step` (Shift for
+/// large); text that isn't a valid hex color is left alone.
+///
+/// A pasted CSS color function (`rgb()`, `hsl()`, `hwb()`) is accepted
+/// too and replaced by its hex on commit.
+///
+/// With `complete_names`, a partial color name (e.g. "corn") shows its
+/// first match (e.g. "cornflowerblue") in place of the caption, and Tab or
+/// Enter replaces the text with that color's hex.
+///
+/// `caption` is shown under the field (e.g. "HEX").
+pub(crate) fn hex_input(
+    hex_signal: RwSignal<String>,
+    step: StepSize,
+    validity: Validity,
+    blur: WindowBlur,
+    complete_names: bool,
+    caption: &'static str,
+) -> impl IntoView {
     let text = RwSignal::new(hex_signal.get_untracked());
+    validity.track(move || SolidColor::from_css(&text.get()).is_some());
 
     // External hex_signal -> text (only update if not equivalent)
     create_effect(move |_| {
@@ -136,7 +421,10 @@ pub(crate) fn hex_input(hex_signal: RwSignal<String>) -> impl IntoView {
 
     let on_commit = move || {
         let raw = text.get_untracked();
-        let normalized = crate::math::normalize_hex(&raw);
+        let normalized = match SolidColor::from_css(&raw) {
+            Some(c) if raw.trim_end().ends_with(')') => c.to_hex(),
+            _ => crate::math::normalize_hex(&raw),
+        };
         if raw != normalized {
             text.set(normalized.clone());
         }
@@ -146,6 +434,38 @@ pub(crate) fn hex_input(hex_signal: RwSignal<String>) -> impl IntoView {
     };
     let on_commit_clone = on_commit;
 
+    let suggestion = move || {
+        if complete_names {
+            name_completion(&text.get())
+        } else {
+            None
+        }
+    };
+
+    // Replace a partial name with its completion's hex; false if none
+    let accept_completion = move || {
+        if !complete_names {
+            return false;
+        }
+        let Some(c) = name_completion(&text.get_untracked()).and_then(SolidColor::from_name) else {
+            return false;
+        };
+        text.set(c.to_hex());
+        on_commit_clone();
+        true
+    };
+
+    // Arrow-key nudge of HSL lightness by `delta`
+    let nudge = move |delta: f64| {
+        let Some(next) = nudged_hex(&text.get_untracked(), delta) else {
+            return;
+        };
+        text.set(next.clone());
+        if hex_signal.get_untracked() != next {
+            hex_signal.set(next);
+        }
+    };
+
     v_stack((
         h_stack((
             label(|| "#").style(|s| {
@@ -176,45 +496,122 @@ pub(crate) fn hex_input(hex_signal: RwSignal<String>) -> impl IntoView {
                         })
                 })
                 .on_event_stop(floem::event::EventListener::FocusLost, move |_| {
-                    on_commit();
+                    if blur.commits(SolidColor::from_css(&text.get_untracked()).is_some()) {
+                        on_commit();
+                    }
                 })
                 .on_event(floem::event::EventListener::KeyDown, move |e| {
-                    if let floem::event::Event::KeyDown(ke) = e
-                        && ke.key.logical_key
-                            == floem::keyboard::Key::Named(floem::keyboard::NamedKey::Enter)
-                    {
-                        on_commit_clone();
-                        return EventPropagation::Stop;
+                    if let floem::event::Event::KeyDown(ke) = e {
+                        let large = ke.modifiers.contains(floem::keyboard::Modifiers::SHIFT);
+                        match &ke.key.logical_key {
+                            floem::keyboard::Key::Named(floem::keyboard::NamedKey::Enter) => {
+                                if !accept_completion() {
+                                    on_commit_clone();
+                                }
+                                return EventPropagation::Stop;
+                            }
+                            floem::keyboard::Key::Named(floem::keyboard::NamedKey::Tab)
+                                if accept_completion() =>
+                            {
+                                return EventPropagation::Stop;
+                            }
+                            floem::keyboard::Key::Named(floem::keyboard::NamedKey::ArrowUp) => {
+                                nudge(step.get(large));
+                                return EventPropagation::Stop;
+                            }
+                            floem::keyboard::Key::Named(floem::keyboard::NamedKey::ArrowDown) => {
+                                nudge(-step.get(large));
+                                return EventPropagation::Stop;
+                            }
+                            _ => {}
+                        }
                     }
                     EventPropagation::Continue
                 }),
         ))
         .style(|s| s.items_center().gap(1.0)),
-        label(|| "HEX").style(|s| {
+        label(move || suggestion().unwrap_or(caption).to_string()).style(move |s| {
             s.font_size(constants::LABEL_FONT)
                 .margin_top(2.0)
                 .color(Color::rgb8(84, 84, 84))
+                .apply_if(suggestion().is_some(), |s| {
+                    s.color(Color::rgb8(0, 102, 204))
+                })
                 .justify_content(Some(floem::taffy::AlignContent::Center))
         }),
     ))
     .style(|s| s.items_center().gap(1.0))
 }
 
-/// An editable percentage input for alpha (0–100%).
+/// Format a normalized alpha for display in `mode`.
+#[cfg(feature = "alpha")]
+fn format_alpha(mode: AlphaDisplay, alpha: f64) -> String {
+    match mode {
+        AlphaDisplay::Percent => format!("{}", (alpha * 100.0).round() as i64),
+        AlphaDisplay::HexByte => format!("{:02X}", (alpha * 255.0).round() as u8),
+    }
+}
+
+/// Parse alpha text in `mode` to a normalized 0.0–1.0 value.
 ///
-/// Shows a numeric text field with a `%` label to its right,
-/// input is updated on Enter or focus-lost (tab) and is clamped to 0–100.
+/// Percent accepts any number and clamps to 0–100. Hex-byte requires exactly
+/// two hex digits (`"FF"` → 1.0); anything else is rejected.
 #[cfg(feature = "alpha")]
-pub(crate) fn alpha_input(signal: RwSignal<f64>) -> impl IntoView {
-    let text = RwSignal::new(format!(
-        "{}",
-        (signal.get_untracked() * 100.0).round() as i64
-    ));
+fn parse_alpha(mode: AlphaDisplay, raw: &str) -> Option<f64> {
+    let raw = raw.trim();
+    match mode {
+        AlphaDisplay::Percent => {
+            let num = raw.parse::<f64>().ok()?;
+            Some(num.clamp(0.0, 100.0).round() / 100.0)
+        }
+        AlphaDisplay::HexByte => {
+            if raw.len() != 2 || !raw.chars().all(|c| c.is_ascii_hexdigit()) {
+                return None;
+            }
+            let byte = u8::from_str_radix(raw, 16).ok()?;
+            Some(byte as f64 / 255.0)
+        }
+    }
+}
+
+/// An editable alpha input, as a percentage (0–100%) or a hex byte (00–FF).
+///
+/// Shows a text field with a `percent` label (e.g. `%`) to its right in
+/// percent mode, input is updated on Enter or focus-lost (tab) and is
+/// clamped to 0–100.
+/// Invalid text (e.g. `G5` in hex-byte mode) reverts to the last valid value.
+/// Up/Down arrows nudge the value by `step` display units (Shift for large).
+#[cfg(feature = "alpha")]
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn alpha_input(
+    signal: RwSignal<f64>,
+    step: StepSize,
+    mode: AlphaDisplay,
+    snap: Option<f64>,
+    validity: Validity,
+    blur: WindowBlur,
+    focus: Option<FieldFocus>,
+    percent: &'static str,
+) -> impl IntoView {
+    let snapped = move |a: f64| match snap {
+        Some(step) => crate::math::snap_to_multiple(a, step),
+        None => a,
+    };
+    let max_display = match mode {
+        AlphaDisplay::Percent => 100.0,
+        AlphaDisplay::HexByte => 255.0,
+    };
+    let suffix = match mode {
+        AlphaDisplay::Percent => percent,
+        AlphaDisplay::HexByte => "",
+    };
+    let text = RwSignal::new(format_alpha(mode, signal.get_untracked()));
+    validity.track(move || parse_alpha(mode, &text.get()).is_some());
 
     // Signal → text
     create_effect(move |_| {
         let val = signal.get();
-        let display = format!("{}", (val * 100.0).round() as i64);
+        let display = format_alpha(mode, val);
         if text.get_untracked() != display {
             text.set(display);
         }
@@ -222,26 +619,32 @@ pub(crate) fn alpha_input(signal: RwSignal<f64>) -> impl IntoView {
 
     let on_commit = move || {
         let raw = text.get_untracked();
-        if let Ok(num) = raw.trim().parse::<f64>() {
-            let clamped = num.clamp(0.0, 100.0);
-            let new_display = clamped.round() as i64;
-            let old_display = (signal.get_untracked() * 100.0).round() as i64;
-            if new_display != old_display {
-                signal.set(clamped / 100.0);
-            }
-            let formatted = format!("{}", new_display);
-            if raw.trim() != formatted {
-                text.set(formatted);
-            }
-        } else {
-            let formatted = format!("{}", (signal.get_untracked() * 100.0).round() as i64);
-            if raw != formatted {
-                text.set(formatted);
-            }
+        if let Some(new_alpha) = parse_alpha(mode, &raw).map(snapped)
+            && format_alpha(mode, new_alpha) != format_alpha(mode, signal.get_untracked())
+        {
+            signal.set(new_alpha);
+        }
+        // Reformat, or revert invalid text to the current value
+        let formatted = format_alpha(mode, signal.get_untracked());
+        if raw != formatted {
+            text.set(formatted);
         }
     };
     let on_commit_clone = on_commit;
 
+    // Arrow-key nudge by `delta` display units
+    let step_by = move |delta: f64| {
+        if let Some(step) = snap {
+            // One increment per press; smaller steps would round back
+            let current = signal.get_untracked();
+            signal.set(snapped(current + delta.signum() * step));
+            return;
+        }
+        let current = (signal.get_untracked() * max_display).round();
+        let next = (current + delta).clamp(0.0, max_display);
+        signal.set(next / max_display);
+    };
+
     h_stack((
         text_input(text)
             .style(|s| {
@@ -265,20 +668,41 @@ pub(crate) fn alpha_input(signal: RwSignal<f64>) -> impl IntoView {
                             .outline_color(Color::rgba8(179, 215, 255, 128))
                     })
             })
+            .on_event_cont(floem::event::EventListener::FocusGained, move |_| {
+                if let Some(focus) = focus {
+                    focus.gained();
+                }
+            })
             .on_event_stop(floem::event::EventListener::FocusLost, move |_| {
-                on_commit();
+                if let Some(focus) = focus {
+                    focus.lost();
+                }
+                if blur.commits(parse_alpha(mode, &text.get_untracked()).is_some()) {
+                    on_commit();
+                }
             })
             .on_event(floem::event::EventListener::KeyDown, move |e| {
-                if let floem::event::Event::KeyDown(ke) = e
-                    && ke.key.logical_key
-                        == floem::keyboard::Key::Named(floem::keyboard::NamedKey::Enter)
-                {
-                    on_commit_clone();
-                    return EventPropagation::Stop;
+                if let floem::event::Event::KeyDown(ke) = e {
+                    let large = ke.modifiers.contains(floem::keyboard::Modifiers::SHIFT);
+                    match &ke.key.logical_key {
+                        floem::keyboard::Key::Named(floem::keyboard::NamedKey::Enter) => {
+                            on_commit_clone();
+                            return EventPropagation::Stop;
+                        }
+                        floem::keyboard::Key::Named(floem::keyboard::NamedKey::ArrowUp) => {
+                            step_by(step.get(large));
+                            return EventPropagation::Stop;
+                        }
+                        floem::keyboard::Key::Named(floem::keyboard::NamedKey::ArrowDown) => {
+                            step_by(-step.get(large));
+                            return EventPropagation::Stop;
+                        }
+                        _ => {}
+                    }
                 }
                 EventPropagation::Continue
             }),
-        label(|| "%").style(|s| {
+        label(move || suffix).style(|s| {
             s.font_size(constants::LABEL_FONT)
                 .color(Color::rgb8(84, 84, 84))
         }),
@@ -286,21 +710,81 @@ pub(crate) fn alpha_input(signal: RwSignal<f64>) -> impl IntoView {
     .style(|s| s.items_center().gap(2.0))
 }
 
-/// A small copy button that copies the result of `get_text` to the clipboard.
-pub(crate) fn copy_button(get_text: impl Fn() -> String + 'static) -> impl IntoView {
+/// Clicks within this window of a clipboard write are coalesced into one
+/// trailing write.
+const COPY_DEBOUNCE: Duration = Duration::from_millis(150);
+
+/// A small copy button that copies the result of `get_text` to `clipboard`.
+///
+/// The first click copies immediately. Further clicks within
+/// [`COPY_DEBOUNCE`] schedule a single trailing write of the latest text, so
+/// rapid clicking doesn't thrash the clipboard.
+pub(crate) fn copy_button(
+    clipboard: SharedClipboard,
+    get_text: impl Fn() -> String + 'static,
+) -> impl IntoView {
+    copy_button_with_icon(lucide_icons::Icon::Copy, clipboard, get_text)
+}
+
+/// [`copy_button`] showing `icon`, for copying something other than the
+/// row's own value.
+pub(crate) fn copy_button_with_icon(
+    icon: lucide_icons::Icon,
+    clipboard: SharedClipboard,
+    get_text: impl Fn() -> String + 'static,
+) -> impl IntoView {
+    let get_text = Rc::new(get_text);
+    let last_write: Rc<Cell<Option<Instant>>> = Rc::new(Cell::new(None));
+    let pending = Rc::new(Cell::new(false));
+    icon_button(icon, move || {
+        let elapsed = last_write.get().map(|t| t.elapsed());
+        match elapsed {
+            Some(elapsed) if elapsed < COPY_DEBOUNCE => {
+                if pending.replace(true) {
+                    return;
+                }
+                let (get_text, last_write, pending, clipboard) = (
+                    get_text.clone(),
+                    last_write.clone(),
+                    pending.clone(),
+                    clipboard.clone(),
+                );
+                floem::action::exec_after(COPY_DEBOUNCE - elapsed, move |_| {
+                    pending.set(false);
+                    last_write.set(Some(Instant::now()));
+                    clipboard.0.set_text(&get_text());
+                });
+            }
+            _ => {
+                last_write.set(Some(Instant::now()));
+                clipboard.0.set_text(&get_text());
+            }
+        }
+    })
+}
+
+/// A small Lucide icon button that calls `on_click` when released. Without
+/// the icon font, shows a text label and widens to fit it.
+pub(crate) fn icon_button(
+    icon: lucide_icons::Icon,
+    on_click: impl Fn() + 'static,
+) -> impl IntoView {
     let pressed = RwSignal::new(false);
-    container(
-        label(|| lucide_icons::Icon::Copy.unicode().to_string()).style(move |s| {
-            let c = if pressed.get() {
-                Color::rgb8(80, 80, 80)
-            } else {
-                Color::rgb8(120, 120, 120)
-            };
-            s.font_size(14.0).font_family("lucide".to_string()).color(c)
-        }),
-    )
-    .style(|s| {
-        s.size(20.0, 20.0)
+    let glyph = icon_font::available();
+    container(label(move || icon_font::text(icon)).style(move |s| {
+        let c = if pressed.get() {
+            Color::rgb8(80, 80, 80)
+        } else {
+            Color::rgb8(120, 120, 120)
+        };
+        icon_font::font(s)
+            .font_size(if glyph { 14.0 } else { constants::LABEL_FONT })
+            .color(c)
+    }))
+    .style(move |s| {
+        s.height(20.0)
+            .min_width(20.0)
+            .apply_if(!glyph, |s| s.padding_horiz(3.0))
             .items_center()
             .justify_center()
             .border_radius(3.0)
@@ -313,12 +797,222 @@ pub(crate) fn copy_button(get_text: impl Fn() -> String + 'static) -> impl IntoV
     })
     .on_event_stop(floem::event::EventListener::PointerUp, move |_| {
         pressed.set(false);
-        copy_to_clipboard(&get_text());
+        on_click();
     })
 }
 
-fn copy_to_clipboard(text: &str) {
-    if let Ok(mut clipboard) = arboard::Clipboard::new() {
-        let _ = clipboard.set_text(text);
+/// A small clickable color chip that calls `on_click` when released.
+pub(crate) fn color_chip(color: SolidColor, on_click: impl Fn() + 'static) -> impl IntoView {
+    empty()
+        .style(move |s| {
+            s.size(14.0, 14.0)
+                .border_radius(3.0)
+                .border(1.0)
+                .border_color(Color::rgb8(180, 180, 180))
+                .background(Color::from(color))
+                .cursor(floem::style::CursorStyle::Pointer)
+        })
+        .on_event_stop(floem::event::EventListener::PointerUp, move |_| {
+            on_click();
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "alpha")]
+    #[test]
+    fn parse_alpha_hex_byte() {
+        let parse = |raw| parse_alpha(AlphaDisplay::HexByte, raw);
+        assert_eq!(parse("FF"), Some(1.0));
+        assert_eq!(parse("00"), Some(0.0));
+        assert_eq!(parse("80"), Some(128.0 / 255.0));
+        assert_eq!(parse("ff"), Some(1.0));
+        assert_eq!(parse("ZZ"), None);
+        assert_eq!(parse("G5"), None);
+        assert_eq!(parse("1"), None);
+        assert_eq!(parse("100"), None);
+        assert_eq!(parse("+F"), None);
+    }
+
+    #[cfg(feature = "alpha")]
+    #[test]
+    fn format_alpha_round_trips_hex_bytes() {
+        for byte in [0u8, 1, 0x80, 0xFE, 0xFF] {
+            let text = format!("{byte:02X}");
+            let alpha = parse_alpha(AlphaDisplay::HexByte, &text).unwrap();
+            assert_eq!(format_alpha(AlphaDisplay::HexByte, alpha), text);
+        }
+    }
+
+    #[test]
+    fn hex_nudge_steps_lightness() {
+        assert_eq!(nudged_hex("000000", 0.2).as_deref(), Some("333333"));
+        assert_eq!(nudged_hex("#333333", -0.2).as_deref(), Some("000000"));
+        // Hue, saturation and alpha are kept
+        assert_eq!(nudged_hex("FF000080", -0.2).as_deref(), Some("99000080"));
+        // Clamped at white
+        assert_eq!(nudged_hex("FFFFFF", 0.1).as_deref(), Some("FFFFFF"));
+    }
+
+    #[test]
+    fn hex_nudge_leaves_invalid_text_alone() {
+        assert_eq!(nudged_hex("12345", 0.1), None);
+        assert_eq!(nudged_hex("zz", 0.1), None);
+        assert_eq!(nudged_hex("", -0.1), None);
+    }
+
+    #[test]
+    fn hex_byte_fields_parse_each_channel() {
+        assert_eq!(parse_hex_byte("3B"), Some(59.0));
+        assert_eq!(parse_hex_byte("82"), Some(130.0));
+        assert_eq!(parse_hex_byte(" f6 "), Some(246.0));
+        assert_eq!(parse_hex_byte("00"), Some(0.0));
+        assert_eq!(parse_hex_byte("FF"), Some(255.0));
+        assert_eq!(parse_hex_byte("G0"), None);
+        assert_eq!(parse_hex_byte("100"), None);
+        assert_eq!(parse_hex_byte(""), None);
+    }
+
+    #[test]
+    fn hex_byte_fields_combine_into_the_color() {
+        let [r, g, b] = ["3B", "82", "F6"].map(|t| parse_hex_byte(t).unwrap() / 255.0);
+        assert_eq!(SolidColor::from_rgba(r, g, b, 1.0).to_hex(), "3B82F6");
+    }
+
+    #[test]
+    fn validity_flips_while_any_field_is_invalid() {
+        let validity = Validity::new();
+        let hex = RwSignal::new("3B82F6".to_string());
+        let hue = RwSignal::new("217".to_string());
+        validity.track(move || SolidColor::from_css(&hex.get()).is_some());
+        validity.track(move || hue.get().parse::<f64>().is_ok());
+        assert!(validity.is_valid());
+
+        hex.set("3B82F".to_string());
+        assert!(!validity.is_valid());
+        hue.set("21x".to_string());
+        assert!(!validity.is_valid());
+        hex.set("3B82F6".to_string());
+        assert!(!validity.is_valid());
+        hue.set("21".to_string());
+        assert!(validity.is_valid());
+    }
+
+    #[test]
+    fn validity_counts_each_field_once() {
+        let validity = Validity::new();
+        let hex = RwSignal::new("zz".to_string());
+        validity.track(move || SolidColor::from_css(&hex.get()).is_some());
+        assert!(!validity.is_valid());
+        // Staying invalid doesn't count the field again
+        hex.set("zzz".to_string());
+        hex.set("3B82F6".to_string());
+        assert!(validity.is_valid());
+    }
+
+    fn linked(value: f64, live: bool) -> (RwSignal<String>, RwSignal<f64>) {
+        let signal = RwSignal::new(value);
+        let text = RwSignal::new((value * 360.0).round().to_string());
+        link_text(
+            text,
+            signal,
+            360.0,
+            live,
+            |v| v.to_string(),
+            |s| s.parse::<f64>().ok(),
+        );
+        (text, signal)
+    }
+
+    #[test]
+    fn live_field_updates_on_each_valid_keystroke() {
+        let (text, signal) = linked(0.0, true);
+        for (typed, want) in [("1", 1.0), ("18", 18.0), ("180", 180.0)] {
+            text.set(typed.to_string());
+            assert_eq!((signal.get_untracked() * 360.0).round(), want);
+        }
+    }
+
+    #[test]
+    fn live_field_skips_partial_and_out_of_range_text() {
+        let (text, signal) = linked(0.5, true);
+        for typed in ["", "-", "400"] {
+            text.set(typed.to_string());
+            assert_eq!(signal.get_untracked(), 0.5, "{typed:?}");
+        }
+    }
+
+    #[test]
+    fn live_field_keeps_equivalent_text_as_typed() {
+        let (text, signal) = linked(0.0, true);
+        text.set("05".to_string());
+        assert_eq!((signal.get_untracked() * 360.0).round(), 5.0);
+        assert_eq!(text.get_untracked(), "05");
+    }
+
+    #[test]
+    fn field_without_live_waits_for_commit() {
+        let (text, signal) = linked(0.0, false);
+        text.set("90".to_string());
+        assert_eq!(signal.get_untracked(), 0.0);
+        signal.set(0.25);
+        assert_eq!(text.get_untracked(), "90");
+    }
+
+    #[test]
+    fn blur_within_the_window_always_commits() {
+        for policy in [
+            WindowBlurCommit::Commit,
+            WindowBlurCommit::CommitValid,
+            WindowBlurCommit::Keep,
+        ] {
+            let blur = WindowBlur::new(policy);
+            assert!(blur.commits(true) && blur.commits(false), "{policy:?}");
+        }
+    }
+
+    #[test]
+    fn window_switch_follows_the_policy() {
+        let commits = |policy| {
+            let blur = WindowBlur::new(policy);
+            blur.set_active(false);
+            (blur.commits(true), blur.commits(false))
+        };
+        assert_eq!(commits(WindowBlurCommit::Commit), (true, true));
+        assert_eq!(commits(WindowBlurCommit::CommitValid), (true, false));
+        assert_eq!(commits(WindowBlurCommit::Keep), (false, false));
+    }
+
+    #[test]
+    fn returning_to_the_window_commits_again() {
+        let blur = WindowBlur::new(WindowBlurCommit::Keep);
+        blur.set_active(false);
+        assert!(!blur.commits(true));
+        blur.set_active(true);
+        assert!(blur.commits(true));
+    }
+
+    #[test]
+    fn scrub_moves_one_step_per_scrub_distance() {
+        assert_eq!(scrub_value(180.0, 0.0, 1.0, 360.0), 180.0);
+        assert_eq!(scrub_value(180.0, SCRUB_PIXELS * 10.0, 1.0, 360.0), 190.0);
+        assert_eq!(scrub_value(180.0, -SCRUB_PIXELS * 3.0, 5.0, 360.0), 165.0);
+    }
+
+    #[test]
+    fn scrub_ignores_partial_steps() {
+        assert_eq!(scrub_value(50.0, SCRUB_PIXELS - 0.5, 1.0, 100.0), 50.0);
+        assert_eq!(
+            scrub_value(50.0, -(SCRUB_PIXELS * 2.0 - 0.5), 1.0, 100.0),
+            49.0
+        );
+    }
+
+    #[test]
+    fn scrub_clamps_to_the_display_range() {
+        assert_eq!(scrub_value(98.0, SCRUB_PIXELS * 10.0, 1.0, 100.0), 100.0);
+        assert_eq!(scrub_value(2.0, -SCRUB_PIXELS * 10.0, 1.0, 100.0), 0.0);
     }
 }
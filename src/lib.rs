@@ -19,37 +19,160 @@ mod color;
 
 #[cfg(feature = "alpha")]
 mod alpha_slider;
+mod animate;
+#[cfg(feature = "alpha")]
+mod brightness_alpha_pad;
 mod brightness_slider;
+mod channel_bar;
 #[cfg(feature = "alpha")]
 mod checkerboard;
+mod clipboard;
 mod color_editor;
 mod color_wheel;
+mod config;
 mod constants;
-#[cfg(all(feature = "eyedropper", target_os = "macos"))]
+#[cfg(all(
+    feature = "eyedropper",
+    any(target_os = "macos", target_os = "linux", target_os = "windows")
+))]
 mod eyedropper;
+#[cfg(all(feature = "eyedropper", target_os = "macos"))]
+mod eyedropper_macos;
+#[cfg(all(feature = "wayland", target_os = "linux"))]
+mod eyedropper_wayland;
+#[cfg(all(feature = "eyedropper", target_os = "windows"))]
+mod eyedropper_win;
+#[cfg(all(feature = "eyedropper", target_os = "linux"))]
+mod eyedropper_x11;
+mod handle;
+#[cfg(feature = "image")]
+mod heatmap;
+mod highlight;
+mod history;
+mod icon_font;
 mod inputs;
 mod math;
+mod named;
+mod none_swatch;
+#[cfg(all(
+    target_os = "macos",
+    any(feature = "eyedropper", feature = "system-colors")
+))]
+mod ns_color;
+mod optional_picker;
+pub mod palette;
+mod saturation_slider;
+mod swatch;
+#[cfg(feature = "image")]
+mod swatch_image;
+mod sync;
+#[cfg(all(feature = "system-colors", target_os = "macos"))]
+mod system_colors;
+mod theme;
+mod ticks;
 
-pub use color::SolidColor;
-
-use std::sync::Once;
+pub use clipboard::{Clipboard, SharedClipboard, SystemClipboard};
+pub use color::{BlendSpace, ColorParseError, SolidColor};
+pub use config::{
+    AlphaDisplay, ChannelOrder, EditorLayout, GroundTruth, HueUnit, Labels, OutOfGamutCallback,
+    PickerConfig, ResponseCurve, RgbDisplay, Spacing, StepSize, Steps, WheelMode, WindowBlurCommit,
+};
+pub use handle::{PickerHandle, PickerState};
+#[cfg(feature = "image")]
+pub use swatch_image::{copy_swatch_image, swatch_png};
+pub use theme::Theme;
 
 use floem::prelude::*;
-use floem::reactive::RwSignal;
-use floem::text::FONT_SYSTEM;
-
-static LOAD_LUCIDE_FONT: Once = Once::new();
+use floem::reactive::{RwSignal, create_memo};
 
 /// Creates the top-level color picker view.
 ///
 /// The picker reads from and writes to `color`. Any external changes to the
 /// signal are reflected in the UI, and user edits update the signal.
 pub fn solid_picker(color: RwSignal<SolidColor>) -> impl IntoView {
-    LOAD_LUCIDE_FONT.call_once(|| {
-        FONT_SYSTEM
-            .lock()
-            .db_mut()
-            .load_font_data(lucide_icons::LUCIDE_FONT_BYTES.to_vec());
-    });
-    color_editor::color_editor(color)
+    solid_picker_with_config(color, PickerConfig::default())
+}
+
+/// Creates a picker preset for HSL workflows: the wheel edits HSL
+/// saturation and lightness, and only the HSL and hex rows are shown.
+///
+/// Equivalent to `solid_picker_with_config(color, PickerConfig::hsl_only())`.
+pub fn solid_picker_hsl(color: RwSignal<SolidColor>) -> impl IntoView {
+    solid_picker_with_config(color, PickerConfig::hsl_only())
+}
+
+/// Creates a compact picker with only the numeric rows and swatch, for
+/// data-entry-heavy contexts. No wheel or sliders are drawn.
+///
+/// Equivalent to `solid_picker_with_config(color, PickerConfig::numeric())`.
+pub fn solid_picker_numeric(color: RwSignal<SolidColor>) -> impl IntoView {
+    solid_picker_with_config(color, PickerConfig::numeric())
+}
+
+/// Creates a picker for opaque colors only: alpha stays 1.0 and no alpha
+/// controls are shown, regardless of the `alpha` feature.
+///
+/// Equivalent to `solid_picker_with_config(color, PickerConfig { opaque: true, ..Default::default() })`.
+pub fn solid_picker_opaque(color: RwSignal<SolidColor>) -> impl IntoView {
+    solid_picker_with_config(
+        color,
+        PickerConfig {
+            opaque: true,
+            ..Default::default()
+        },
+    )
+}
+
+/// Creates a picker with a WCAG contrast badge checking the color against
+/// `contrast_against`. With `None` the badge row is hidden.
+///
+/// Equivalent to `solid_picker_with_config(color, PickerConfig { contrast_against, ..Default::default() })`.
+pub fn solid_picker_with_options(
+    color: RwSignal<SolidColor>,
+    contrast_against: Option<RwSignal<SolidColor>>,
+) -> impl IntoView {
+    solid_picker_with_config(
+        color,
+        PickerConfig {
+            contrast_against,
+            ..Default::default()
+        },
+    )
+}
+
+/// Creates the color picker view with custom [`PickerConfig`] options.
+pub fn solid_picker_with_config(
+    color: RwSignal<SolidColor>,
+    config: PickerConfig,
+) -> impl IntoView {
+    solid_picker_with(color, config).0
+}
+
+/// Creates the color picker view along with a [`PickerHandle`] for
+/// observing its state, such as whether all fields hold valid input.
+pub fn solid_picker_with(
+    color: RwSignal<SolidColor>,
+    config: PickerConfig,
+) -> (impl IntoView, PickerHandle) {
+    icon_font::load();
+    let validity = inputs::Validity::new();
+    let signals = color_editor::EditorSignals::new();
+    let handle = PickerHandle {
+        is_valid: create_memo(move |_| validity.is_valid()),
+        signals,
+        ground_truth: config.ground_truth,
+    };
+    (
+        color_editor::color_editor(color, &config, validity, signals),
+        handle,
+    )
+}
+
+/// Creates a picker for fields that allow "no color".
+///
+/// A toggle above the editor switches between `None` (shown as a slashed
+/// swatch, editor hidden) and `Some`. Editing the color sets `Some`.
+pub fn solid_picker_optional(color: RwSignal<Option<SolidColor>>) -> impl IntoView {
+    icon_font::load();
+    optional_picker::optional_picker(color, &PickerConfig::default())
 }
@@ -62,7 +62,210 @@ pub(crate) fn hsb_to_hsl(h: f64, s_hsb: f64, v: f64) -> (f64, f64, f64) {
     (h, s_hsl, l)
 }
 
-/// Normalize a hex string: uppercase, expand shorthand, default to gray if invalid.
+/// RGB -> naive device CMYK (no color profile). Black is pure K.
+pub(crate) fn rgb_to_cmyk(r: f64, g: f64, b: f64) -> (f64, f64, f64, f64) {
+    let k = 1.0 - r.max(g).max(b);
+    if k >= 1.0 {
+        return (0.0, 0.0, 0.0, 1.0);
+    }
+    let ink = |c: f64| (1.0 - c - k) / (1.0 - k);
+    (ink(r), ink(g), ink(b), k)
+}
+
+/// Naive device CMYK -> RGB
+pub(crate) fn cmyk_to_rgb(c: f64, m: f64, y: f64, k: f64) -> (f64, f64, f64) {
+    let channel = |ink: f64| (1.0 - ink) * (1.0 - k);
+    (channel(c), channel(m), channel(y))
+}
+
+/// sRGB transfer function: encoded channel -> linear light.
+pub(crate) fn srgb_to_linear(c: f64) -> f64 {
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Inverse sRGB transfer function: linear light -> encoded channel.
+pub(crate) fn linear_to_srgb(c: f64) -> f64 {
+    if c <= 0.0031308 {
+        c * 12.92
+    } else {
+        1.055 * c.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+/// WCAG relative luminance of an sRGB color.
+pub(crate) fn relative_luminance(r: f64, g: f64, b: f64) -> f64 {
+    0.2126 * srgb_to_linear(r) + 0.7152 * srgb_to_linear(g) + 0.0722 * srgb_to_linear(b)
+}
+
+/// WCAG contrast ratio between two relative luminances (1.0–21.0).
+pub(crate) fn contrast_ratio(l1: f64, l2: f64) -> f64 {
+    let (hi, lo) = if l1 >= l2 { (l1, l2) } else { (l2, l1) };
+    (hi + 0.05) / (lo + 0.05)
+}
+
+/// Screen luminance of an sRGB color as APCA estimates it: a plain 2.4
+/// power curve rather than the piecewise sRGB transfer function.
+pub(crate) fn apca_luminance(r: f64, g: f64, b: f64) -> f64 {
+    0.2126729 * r.powf(2.4) + 0.7151522 * g.powf(2.4) + 0.0721750 * b.powf(2.4)
+}
+
+/// APCA (0.0.98G-4g) lightness contrast Lc of text luminance `text_y` on
+/// background luminance `bg_y`, from [`apca_luminance`]. About -108 to 106:
+/// positive for dark text on a light background, negative for light on dark.
+pub(crate) fn apca_lc(text_y: f64, bg_y: f64) -> f64 {
+    const BLACK_THRESHOLD: f64 = 0.022;
+    const BLACK_CLAMP: f64 = 1.414;
+    const SCALE: f64 = 1.14;
+    const LOW_OFFSET: f64 = 0.027;
+    const LOW_CLIP: f64 = 0.1;
+    const DELTA_Y_MIN: f64 = 0.0005;
+
+    // Soft-clamp near-black luminances
+    let soft_clamp = |y: f64| {
+        if y > BLACK_THRESHOLD {
+            y
+        } else {
+            y + (BLACK_THRESHOLD - y).powf(BLACK_CLAMP)
+        }
+    };
+    let (text_y, bg_y) = (soft_clamp(text_y), soft_clamp(bg_y));
+    if (bg_y - text_y).abs() < DELTA_Y_MIN {
+        return 0.0;
+    }
+    let lc = if bg_y > text_y {
+        // Dark text on a light background
+        let sapc = (bg_y.powf(0.56) - text_y.powf(0.57)) * SCALE;
+        if sapc < LOW_CLIP {
+            0.0
+        } else {
+            sapc - LOW_OFFSET
+        }
+    } else {
+        // Light text on a dark background
+        let sapc = (bg_y.powf(0.65) - text_y.powf(0.62)) * SCALE;
+        if sapc > -LOW_CLIP {
+            0.0
+        } else {
+            sapc + LOW_OFFSET
+        }
+    };
+    lc * 100.0
+}
+
+/// sRGB -> OKLab (Björn Ottosson). Returns (L, a, b) with L in 0.0–1.0.
+pub(crate) fn srgb_to_oklab(r: f64, g: f64, b: f64) -> (f64, f64, f64) {
+    let (r, g, b) = (srgb_to_linear(r), srgb_to_linear(g), srgb_to_linear(b));
+    let l = (0.4122214708 * r + 0.5363325363 * g + 0.0514459929 * b).cbrt();
+    let m = (0.2119034982 * r + 0.6806995451 * g + 0.1073969566 * b).cbrt();
+    let s = (0.0883024619 * r + 0.2817188376 * g + 0.6299787005 * b).cbrt();
+    (
+        0.2104542553 * l + 0.7936177850 * m - 0.0040720468 * s,
+        1.9779984951 * l - 2.4285922050 * m + 0.4505937099 * s,
+        0.0259040371 * l + 0.7827717662 * m - 0.8086757660 * s,
+    )
+}
+
+/// OKLab -> sRGB. Channels are not clamped and may fall outside 0.0–1.0
+/// for colors outside the sRGB gamut.
+pub(crate) fn oklab_to_srgb(l: f64, a: f64, b: f64) -> (f64, f64, f64) {
+    let l_ = (l + 0.3963377774 * a + 0.2158037573 * b).powi(3);
+    let m_ = (l - 0.1055613458 * a - 0.0638541728 * b).powi(3);
+    let s_ = (l - 0.0894841775 * a - 1.2914855480 * b).powi(3);
+    (
+        linear_to_srgb(4.0767416621 * l_ - 3.3077115913 * m_ + 0.2309699292 * s_),
+        linear_to_srgb(-1.2684380046 * l_ + 2.6097574011 * m_ - 0.3413193965 * s_),
+        linear_to_srgb(-0.0041960863 * l_ - 0.7034186147 * m_ + 1.7076147010 * s_),
+    )
+}
+
+/// OKLCH chroma that CSS writes as `100%`; the editor's chroma field shows
+/// chroma as a percentage of it.
+#[cfg(feature = "oklch")]
+pub(crate) const OKLCH_CHROMA_FULL: f64 = 0.4;
+
+/// OKLab -> OKLCH. Returns (L, C, h) with h in 0.0–1.0.
+pub(crate) fn oklab_to_oklch(l: f64, a: f64, b: f64) -> (f64, f64, f64) {
+    let c = (a * a + b * b).sqrt();
+    let h = (b.atan2(a) / std::f64::consts::TAU).rem_euclid(1.0);
+    (l, c, h)
+}
+
+/// OKLCH -> OKLab. `h` is in 0.0–1.0.
+pub(crate) fn oklch_to_oklab(l: f64, c: f64, h: f64) -> (f64, f64, f64) {
+    let angle = h * std::f64::consts::TAU;
+    (l, c * angle.cos(), c * angle.sin())
+}
+
+/// OKLCH -> sRGB, reducing chroma (keeping L and h) until the color fits
+/// the sRGB gamut. `h` is in 0.0–1.0; `l` is clamped to 0.0–1.0.
+///
+/// Binary search on chroma; the final clamp only removes float noise at the
+/// gamut edge, so hue and lightness are preserved.
+pub(crate) fn oklch_to_srgb_in_gamut(l: f64, c: f64, h: f64) -> (f64, f64, f64) {
+    const EPS: f64 = 1e-6;
+    let l = l.clamp(0.0, 1.0);
+    let rgb_at = |c: f64| {
+        let (l, a, b) = oklch_to_oklab(l, c, h);
+        oklab_to_srgb(l, a, b)
+    };
+    let in_gamut =
+        |(r, g, b): (f64, f64, f64)| [r, g, b].iter().all(|v| (-EPS..=1.0 + EPS).contains(v));
+    let mut rgb = rgb_at(c);
+    if !in_gamut(rgb) {
+        let (mut lo, mut hi) = (0.0, c);
+        for _ in 0..32 {
+            let mid = (lo + hi) / 2.0;
+            if in_gamut(rgb_at(mid)) {
+                lo = mid;
+            } else {
+                hi = mid;
+            }
+        }
+        rgb = rgb_at(lo);
+    }
+    (
+        rgb.0.clamp(0.0, 1.0),
+        rgb.1.clamp(0.0, 1.0),
+        rgb.2.clamp(0.0, 1.0),
+    )
+}
+
+/// Round `value` (0.0–1.0) to the nearest multiple of `1 / divisions`.
+pub(crate) fn quantize(value: f64, divisions: f64) -> f64 {
+    (value * divisions).round() / divisions
+}
+
+/// Round `value` (0.0–1.0) to the nearest multiple of `step`, clamped to
+/// 0.0–1.0. A `step` that doesn't divide 1.0 can't reach 1.0. Non-positive
+/// steps leave `value` unchanged.
+#[cfg_attr(not(feature = "alpha"), allow(dead_code))]
+pub(crate) fn snap_to_multiple(value: f64, step: f64) -> f64 {
+    if step <= 0.0 {
+        return value;
+    }
+    ((value / step).round() * step).clamp(0.0, 1.0)
+}
+
+/// Snap `h` to the nearest hue in `allowed`, measuring distance around the
+/// wheel so 0.95 snaps to 0.0 rather than 0.8. Returns `h` if `allowed` is empty.
+pub(crate) fn snap_hue(h: f64, allowed: &[f64]) -> f64 {
+    let dist = |a: f64| {
+        let d = (a.rem_euclid(1.0) - h).abs();
+        d.min(1.0 - d)
+    };
+    allowed
+        .iter()
+        .copied()
+        .min_by(|x, y| dist(*x).total_cmp(&dist(*y)))
+        .map_or(h, |a| a.rem_euclid(1.0))
+}
+
+/// Normalize a hex string: uppercase, expand shorthand (RGB and RGBA),
+/// default to gray if invalid.
 ///
 /// Returns 6 chars (RRGGBB) when alpha is FF, 8 chars (RRGGBBAA) otherwise.
 pub(crate) fn normalize_hex(hex: &str) -> String {
@@ -70,24 +273,134 @@ pub(crate) fn normalize_hex(hex: &str) -> String {
     if !stripped.chars().all(|c| c.is_ascii_hexdigit()) {
         return "808080".to_string();
     }
-    match stripped.len() {
-        3 => {
-            let mut out = String::with_capacity(6);
-            for c in stripped.chars() {
-                out.push(c);
-                out.push(c);
+    let mut upper = match stripped.len() {
+        3 | 4 => stripped.chars().flat_map(|c| [c, c]).collect::<String>(),
+        6 | 8 => stripped.to_string(),
+        _ => return "808080".to_string(),
+    }
+    .to_uppercase();
+    if upper.len() == 8 && upper.ends_with("FF") {
+        upper.truncate(6);
+    }
+    upper
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn snap_hue_picks_the_closest_allowed_hue() {
+        let allowed = [0.0, 1.0 / 3.0, 2.0 / 3.0];
+        assert_eq!(snap_hue(0.3, &allowed), 1.0 / 3.0);
+        assert_eq!(snap_hue(0.6, &allowed), 2.0 / 3.0);
+        assert_eq!(snap_hue(0.4, &[]), 0.4);
+    }
+
+    #[test]
+    fn snap_hue_measures_around_the_wheel() {
+        // 0.95 is 0.05 from red but 0.15 from 0.8
+        assert_eq!(snap_hue(0.95, &[0.0, 0.8]), 0.0);
+        assert_eq!(snap_hue(0.05, &[0.9, 0.3]), 0.9);
+        // Allowed hues outside 0.0–1.0 wrap too
+        assert_eq!(snap_hue(0.02, &[1.0, 0.5]), 0.0);
+    }
+
+    #[test]
+    fn quantized_values_match_the_displayed_integers() {
+        for divisions in [100.0, 255.0, 360.0] {
+            for i in 0..=1000 {
+                let v = i as f64 / 1000.0;
+                let q = quantize(v, divisions);
+                // What a numeric field shows, before and after quantizing
+                let shown = (v * divisions).round();
+                assert_eq!((q * divisions).round(), shown);
+                assert!((q * divisions - shown).abs() < 1e-9, "{v} / {divisions}");
+                assert_eq!(quantize(q, divisions), q);
             }
-            out.to_uppercase()
         }
-        6 => stripped.to_uppercase(),
-        8 => {
-            let upper = stripped.to_uppercase();
-            if upper.ends_with("FF") {
-                upper[..6].to_string()
-            } else {
-                upper
+    }
+
+    #[test]
+    fn oklch_gamut_mapping_keeps_hue_and_lightness() {
+        for h in [0.0, 0.15, 0.4, 0.7, 0.9] {
+            for l in [0.3, 0.6, 0.85] {
+                let (r, g, b) = oklch_to_srgb_in_gamut(l, 0.4, h);
+                assert!([r, g, b].iter().all(|c| (0.0..=1.0).contains(c)));
+                let (l2, a2, b2) = srgb_to_oklab(r, g, b);
+                let (_, c2, h2) = oklab_to_oklch(l2, a2, b2);
+                let dh = (h2 - h).rem_euclid(1.0);
+                assert!(dh.min(1.0 - dh) < 0.002, "hue {h} -> {h2}");
+                assert!((l2 - l).abs() < 0.002, "lightness {l} -> {l2}");
+                assert!(c2 < 0.4);
             }
         }
-        _ => "808080".to_string(),
+    }
+
+    #[test]
+    fn oklch_gamut_mapping_leaves_in_gamut_colors() {
+        let (l, a, b) = srgb_to_oklab(0.2, 0.5, 0.8);
+        let (_, c, h) = oklab_to_oklch(l, a, b);
+        let (r, g, b) = oklch_to_srgb_in_gamut(l, c, h);
+        // The OKLab matrices are only accurate to about 1e-7
+        assert!((r - 0.2).abs() < 1e-6);
+        assert!((g - 0.5).abs() < 1e-6);
+        assert!((b - 0.8).abs() < 1e-6);
+    }
+
+    fn assert_close(a: f64, b: f64) {
+        assert!((a - b).abs() < 1e-9, "{a} != {b}");
+    }
+
+    #[test]
+    fn snap_to_multiple_rounds_to_the_step() {
+        assert_close(snap_to_multiple(0.33, 0.1), 0.3);
+        assert_close(snap_to_multiple(0.37, 0.25), 0.25);
+        assert_close(snap_to_multiple(0.38, 0.25), 0.5);
+        assert_eq!(snap_to_multiple(0.99, 0.25), 1.0);
+    }
+
+    #[test]
+    fn snap_to_multiple_edge_cases() {
+        // 0.3 doesn't divide 1.0: the top value is 0.9
+        assert_close(snap_to_multiple(1.0, 0.3), 0.9);
+        assert_eq!(snap_to_multiple(0.42, 0.0), 0.42);
+        assert_eq!(snap_to_multiple(0.42, -0.1), 0.42);
+        assert_eq!(snap_to_multiple(1.2, 0.5), 1.0);
+    }
+
+    #[test]
+    fn normalize_hex_matches_parse_and_format() {
+        use crate::color::SolidColor;
+
+        const DIGITS: &[u8] = b"0123456789abcdefABCDEF";
+        let mut seed = 0x2545_F491_u32;
+        let mut next = move || {
+            seed ^= seed << 13;
+            seed ^= seed >> 17;
+            seed ^= seed << 5;
+            seed as usize
+        };
+        for i in 0..2000 {
+            let len = [3, 4, 6, 8][i % 4];
+            let digits: String = (0..len)
+                .map(|_| DIGITS[next() % DIGITS.len()] as char)
+                .collect();
+            let hex = if i % 3 == 0 {
+                format!("#{digits}")
+            } else {
+                digits
+            };
+            let parsed = SolidColor::from_hex(&hex).unwrap();
+            assert_eq!(parsed.to_hex(), normalize_hex(&hex), "{hex}");
+        }
+    }
+
+    #[test]
+    fn normalize_hex_drops_opaque_alpha() {
+        assert_eq!(normalize_hex("#3b82f6ff"), "3B82F6");
+        assert_eq!(normalize_hex("3B82F680"), "3B82F680");
+        assert_eq!(normalize_hex("fffF"), "FFFFFF");
+        assert_eq!(normalize_hex("3B82F"), "808080");
     }
 }
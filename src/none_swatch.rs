@@ -0,0 +1,63 @@
+//! "No color" swatch: white with a red diagonal slash.
+
+use floem::kurbo::{Line, Rect, Stroke};
+use floem::peniko::Color;
+use floem::views::Decorators;
+use floem::{
+    View, ViewId,
+    context::{ComputeLayoutCx, PaintCx},
+};
+
+use floem_renderer::Renderer;
+
+use crate::constants;
+
+const SLASH: Color = Color::rgb8(220, 38, 38);
+const BORDER: Color = Color::rgb8(180, 180, 180);
+
+pub(crate) struct NoneSwatch {
+    id: ViewId,
+    size: floem::taffy::prelude::Size<f32>,
+}
+
+/// A square swatch marking the absence of a color.
+pub(crate) fn none_swatch() -> NoneSwatch {
+    NoneSwatch {
+        id: ViewId::new(),
+        size: Default::default(),
+    }
+    .style(|s| s.width(32.0).height(32.0))
+}
+
+impl View for NoneSwatch {
+    fn id(&self) -> ViewId {
+        self.id
+    }
+
+    fn compute_layout(&mut self, _cx: &mut ComputeLayoutCx) -> Option<Rect> {
+        let layout = self.id.get_layout().unwrap_or_default();
+        self.size = layout.size;
+        None
+    }
+
+    fn paint(&mut self, cx: &mut PaintCx) {
+        let w = self.size.width as f64;
+        let h = self.size.height as f64;
+        if w == 0.0 || h == 0.0 {
+            return;
+        }
+        let rect = Rect::new(0.0, 0.0, w, h);
+        let radius = constants::RADIUS as f64;
+        let rounded = rect.to_rounded_rect(radius);
+        cx.fill(&rounded, Color::WHITE, 0.0);
+        cx.save();
+        cx.clip(&rounded);
+        cx.stroke(&Line::new((w, 0.0), (0.0, h)), SLASH, &Stroke::new(2.0));
+        cx.restore();
+        cx.stroke(
+            &Rect::new(0.5, 0.5, w - 0.5, h - 0.5).to_rounded_rect(radius),
+            BORDER,
+            &Stroke::new(1.0),
+        );
+    }
+}
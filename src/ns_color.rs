@@ -0,0 +1,51 @@
+//! Shared `NSColor` helpers for the macOS integrations.
+
+use objc2::msg_send;
+use objc2::runtime::{AnyClass, AnyObject};
+
+use crate::color::SolidColor;
+
+/// Convert an `NSColor` to sRGB components `(r, g, b, a)`.
+///
+/// Components are not clamped: wide-gamut colors can land outside 0.0–1.0.
+/// Returns `None` if the color can't be converted to sRGB.
+///
+/// # Safety
+///
+/// `color` must point to a valid `NSColor`.
+pub(crate) unsafe fn ns_color_to_srgb(color: &AnyObject) -> Option<(f64, f64, f64, f64)> {
+    unsafe {
+        let ns_cs_cls = AnyClass::get("NSColorSpace")?;
+        let srgb: *const AnyObject = msg_send![ns_cs_cls, sRGBColorSpace];
+        if srgb.is_null() {
+            return None;
+        }
+        let srgb_color: *const AnyObject = msg_send![color, colorUsingColorSpace: &*srgb];
+        if srgb_color.is_null() {
+            return None;
+        }
+        let mut r: f64 = 0.0;
+        let mut g: f64 = 0.0;
+        let mut b: f64 = 0.0;
+        let mut a: f64 = 0.0;
+        let _: () = msg_send![
+            &*srgb_color,
+            getRed: &mut r,
+            green: &mut g,
+            blue: &mut b,
+            alpha: &mut a
+        ];
+        Some((r, g, b, a))
+    }
+}
+
+/// Convert an `NSColor` to a [`SolidColor`], clamping to sRGB.
+///
+/// # Safety
+///
+/// `color` must point to a valid `NSColor`.
+#[cfg_attr(not(feature = "system-colors"), allow(dead_code))]
+pub(crate) unsafe fn ns_color_to_solid(color: &AnyObject) -> Option<SolidColor> {
+    let (r, g, b, a) = unsafe { ns_color_to_srgb(color)? };
+    Some(SolidColor::from_rgba(r, g, b, a))
+}
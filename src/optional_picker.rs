@@ -0,0 +1,83 @@
+//! Picker bound to an optional color, with a toggle for "no color".
+
+use floem::prelude::*;
+use floem::reactive::{RwSignal, SignalGet, SignalUpdate, create_effect};
+
+use crate::color::SolidColor;
+use crate::color_editor::{EditorSignals, color_editor};
+use crate::config::PickerConfig;
+use crate::constants;
+use crate::inputs::Validity;
+use crate::none_swatch::none_swatch;
+
+/// Wraps the editor around a non-optional working color.
+///
+/// The editor stays hidden while `color` is `None`. Toggling on restores the
+/// last edited color; any edit while enabled writes `Some`.
+pub(crate) fn optional_picker(
+    color: RwSignal<Option<SolidColor>>,
+    config: &PickerConfig,
+) -> impl IntoView + use<> {
+    let working = RwSignal::new(color.get_untracked().unwrap_or_default());
+
+    // External Some(c) -> working color
+    create_effect(move |_| {
+        if let Some(c) = color.get()
+            && working.get_untracked() != c
+        {
+            working.set(c);
+        }
+    });
+
+    // Editor edits -> Some(c). Skips the first run so an initial None stays None.
+    create_effect(move |prev: Option<()>| {
+        let c = working.get();
+        if prev.is_some() && color.get_untracked() != Some(c) {
+            color.set(Some(c));
+        }
+    });
+
+    let enabled = move || color.get().is_some();
+    let toggle = move || {
+        if color.get_untracked().is_some() {
+            color.set(None);
+        } else {
+            color.set(Some(working.get_untracked()));
+        }
+    };
+
+    v_stack((
+        h_stack((
+            // Checkbox
+            empty().style(move |s| {
+                let on = enabled();
+                s.width(14.0)
+                    .height(14.0)
+                    .border(1.0)
+                    .border_radius(3.0)
+                    .border_color(Color::rgb8(160, 160, 160))
+                    .apply_if(on, |s| {
+                        s.background(Color::rgb8(59, 130, 246))
+                            .border_color(Color::rgb8(59, 130, 246))
+                    })
+                    .apply_if(!on, |s| s.background(Color::WHITE))
+            }),
+            label(move || if enabled() { "Color" } else { "No color" }).style(|s| {
+                s.font_size(constants::LABEL_FONT + 2.0)
+                    .color(Color::rgb8(84, 84, 84))
+            }),
+            empty().style(|s| s.flex_grow(1.0)),
+            none_swatch().style(move |s| s.apply_if(enabled(), |s| s.hide())),
+        ))
+        .on_click_stop(move |_| toggle())
+        .style(|s| {
+            s.items_center()
+                .gap(constants::GAP)
+                .padding(constants::PADDING)
+                .cursor(floem::style::CursorStyle::Pointer)
+        }),
+        color_editor(working, config, Validity::new(), EditorSignals::new())
+            .style(move |s| s.apply_if(!enabled(), |s| s.hide())),
+    ))
+    .style(|s| s.background(Color::rgb8(242, 242, 242)))
+}
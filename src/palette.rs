@@ -0,0 +1,344 @@
+//! Palette and color-harmony helpers built on [`SolidColor`].
+//!
+//! Hues are normalized to 0.0–1.0, matching the rest of the crate.
+//! Tonal helpers work in OKLCH, so equal lightness steps look roughly even.
+
+use crate::color::SolidColor;
+use crate::constants;
+use crate::math;
+
+/// Hue opposite `hue` on the color wheel (180° rotation), in 0.0–1.0.
+pub fn complementary_hue(hue: f64) -> f64 {
+    (hue + 0.5).rem_euclid(1.0)
+}
+
+/// Complementary color: hue rotated by 180°, saturation, brightness and
+/// alpha preserved.
+pub fn complementary(color: &SolidColor) -> SolidColor {
+    let (h, s, b) = color.to_hsb();
+    SolidColor::from_hsb(complementary_hue(h), s, b, color.a())
+}
+
+/// Snap each channel to the nearest of the six web-safe levels
+/// (0, 51, 102, 153, 204, 255). Exact midpoints round up. Alpha is kept.
+pub fn nearest_web_safe(color: &SolidColor) -> SolidColor {
+    let snap = |c: f64| (c * 5.0).round() / 5.0;
+    SolidColor::from_rgba(snap(color.r()), snap(color.g()), snap(color.b()), color.a())
+}
+
+/// Nearest of the 216 web-safe colors by OKLab distance, which tracks
+/// perceived difference better than snapping channels independently.
+/// Alpha is kept.
+pub fn nearest_web_safe_perceptual(color: &SolidColor) -> SolidColor {
+    let target = math::srgb_to_oklab(color.r(), color.g(), color.b());
+    let mut best = (f64::INFINITY, (0.0, 0.0, 0.0));
+    for r in 0..6 {
+        for g in 0..6 {
+            for b in 0..6 {
+                let rgb = (r as f64 / 5.0, g as f64 / 5.0, b as f64 / 5.0);
+                let lab = math::srgb_to_oklab(rgb.0, rgb.1, rgb.2);
+                let d = (lab.0 - target.0).powi(2)
+                    + (lab.1 - target.1).powi(2)
+                    + (lab.2 - target.2).powi(2);
+                if d < best.0 {
+                    best = (d, rgb);
+                }
+            }
+        }
+    }
+    let (r, g, b) = best.1;
+    SolidColor::from_rgba(r, g, b, color.a())
+}
+
+/// `n` colors with hues evenly spaced around the wheel, starting at
+/// `base`'s hue, each adjusted for WCAG AA contrast (4.5:1) against
+/// `background` with [`SolidColor::adjust_for_contrast`]. Saturation,
+/// brightness and alpha start from `base`. Useful for chart series or
+/// status colors on a known background.
+///
+/// A hue that can't reach AA at any lightness (e.g. on a mid-gray
+/// background) gets its highest-contrast variant instead.
+pub fn accessible_set(base: SolidColor, background: SolidColor, n: usize) -> Vec<SolidColor> {
+    let (h, s, b) = base.to_hsb();
+    (0..n)
+        .map(|i| {
+            let hue = (h + i as f64 / n as f64).rem_euclid(1.0);
+            SolidColor::from_hsb(hue, s, b, base.a())
+                .adjust_for_contrast(&background, constants::CONTRAST_AA)
+        })
+        .collect()
+}
+
+/// Colors at the base color's OKLCH hue and chroma, one per entry of
+/// `lightnesses` (OKLCH L, 0.0–1.0). Chroma is reduced where the base chroma
+/// doesn't fit in sRGB at that lightness. Alpha is kept.
+pub fn tonal_ramp(base: &SolidColor, lightnesses: &[f64]) -> Vec<SolidColor> {
+    let (l, a, b) = math::srgb_to_oklab(base.r(), base.g(), base.b());
+    let (_, chroma, hue) = math::oklab_to_oklch(l, a, b);
+    lightnesses
+        .iter()
+        .map(|&l| {
+            let (r, g, b) = math::oklch_to_srgb_in_gamut(l, chroma, hue);
+            SolidColor::from_rgba(r, g, b, base.a())
+        })
+        .collect()
+}
+
+/// Material tone values: 0, 10, 20, …, 90, plus 95, 99 and 100.
+pub const MATERIAL_TONES: [u8; 13] = [0, 10, 20, 30, 40, 50, 60, 70, 80, 90, 95, 99, 100];
+
+/// The 13 standard Material tones ([`MATERIAL_TONES`]) at the base hue and
+/// chroma.
+///
+/// Approximates Material's HCT by using OKLCH lightness as the tone, so
+/// results are close to but not identical with Material's own palettes.
+/// Tone 0 is black and tone 100 is white.
+pub fn material_tones(base: SolidColor) -> [SolidColor; 13] {
+    let lightnesses = MATERIAL_TONES.map(|t| t as f64 / 100.0);
+    let ramp = tonal_ramp(&base, &lightnesses);
+    std::array::from_fn(|i| ramp[i])
+}
+
+/// A design-token JSON block grouping `ramp` under `name`, e.g.
+/// `{"blue": {"50": "#EEF4FF", "100": "#DCE8FF"}}`.
+///
+/// Each entry's key is its step label (a tone such as `50`, or any other
+/// name). Values are `#`-prefixed hex codes, with alpha only when the color
+/// is translucent. Keys keep the order of `ramp`.
+///
+/// ```rust
+/// use floem_picker::SolidColor;
+/// use floem_picker::palette::{MATERIAL_TONES, material_tones, to_design_tokens};
+///
+/// let base = SolidColor::from_rgb(0, 102, 255);
+/// let ramp: Vec<_> = MATERIAL_TONES.into_iter().zip(material_tones(base)).collect();
+/// // {"blue": {"0": "#000000", "10": …, "100": "#FFFFFF"}}
+/// let json = to_design_tokens("blue", &ramp);
+/// ```
+pub fn to_design_tokens(name: &str, ramp: &[(impl std::fmt::Display, SolidColor)]) -> String {
+    let entries: Vec<String> = ramp
+        .iter()
+        .map(|(step, color)| {
+            format!(
+                "    {}: \"#{}\"",
+                json_string(&step.to_string()),
+                color.to_hex()
+            )
+        })
+        .collect();
+    format!(
+        "{{\n  {}: {{\n{}\n  }}\n}}",
+        json_string(name),
+        entries.join(",\n")
+    )
+}
+
+/// `s` as a quoted JSON string.
+fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// Perceptual distance between two colors: Euclidean distance in OKLab
+/// (ΔEok), ignoring alpha. About 0.02 is a just-noticeable difference.
+pub fn delta_e(a: &SolidColor, b: &SolidColor) -> f64 {
+    let (l1, a1, b1) = math::srgb_to_oklab(a.r(), a.g(), a.b());
+    let (l2, a2, b2) = math::srgb_to_oklab(b.r(), b.g(), b.b());
+    ((l1 - l2).powi(2) + (a1 - a2).powi(2) + (b1 - b2).powi(2)).sqrt()
+}
+
+/// Index of the color in `colors` closest to `target` by [`delta_e`], with
+/// that distance. `None` if `colors` is empty; ties go to the first.
+pub fn nearest(colors: &[SolidColor], target: &SolidColor) -> Option<(usize, f64)> {
+    colors
+        .iter()
+        .map(|c| delta_e(c, target))
+        .enumerate()
+        .min_by(|(_, a), (_, b)| a.total_cmp(b))
+}
+
+/// The palette entry closest to `color` by [`delta_e`], if `palette` is
+/// non-empty.
+pub fn nearest_in<'a>(
+    palette: &'a [(String, SolidColor)],
+    color: &SolidColor,
+) -> Option<&'a (String, SolidColor)> {
+    palette
+        .iter()
+        .min_by(|(_, a), (_, b)| delta_e(a, color).total_cmp(&delta_e(b, color)))
+}
+
+/// Name of the palette entry matching `color`: the nearest entry, if it is
+/// within `threshold` [`delta_e`]. A threshold of 0.0 requires an exact match.
+pub fn name_in<'a>(
+    palette: &'a [(String, SolidColor)],
+    color: &SolidColor,
+    threshold: f64,
+) -> Option<&'a str> {
+    nearest_in(palette, color)
+        .filter(|(_, c)| delta_e(c, color) <= threshold)
+        .map(|(name, _)| name.as_str())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nearest_web_safe_snaps_each_channel() {
+        let snap = |r, g, b| nearest_web_safe(&SolidColor::from_rgb(r, g, b)).to_rgb();
+        assert_eq!(snap(25, 26, 0), (0, 51, 0));
+        assert_eq!(snap(127, 128, 255), (102, 153, 255));
+        assert_eq!(snap(230, 229, 51), (255, 204, 51));
+    }
+
+    #[test]
+    fn nearest_web_safe_midpoints_round_up() {
+        // 0.1 is exactly halfway between 0 and 51
+        let c = SolidColor::from_rgba(0.1, 0.1, 0.1, 0.5);
+        let snapped = nearest_web_safe(&c);
+        assert_eq!(snapped.to_rgb(), (51, 51, 51));
+        assert_eq!(snapped.a(), 0.5);
+    }
+
+    #[test]
+    fn nearest_web_safe_perceptual_lands_on_the_grid() {
+        let web_safe = SolidColor::from_rgb(0, 153, 255);
+        assert_eq!(
+            nearest_web_safe_perceptual(&web_safe).to_rgb(),
+            (0, 153, 255)
+        );
+        let (r, g, b) = nearest_web_safe_perceptual(&SolidColor::from_rgb(59, 130, 246)).to_rgb();
+        assert!([r, g, b].iter().all(|c| c % 51 == 0));
+    }
+
+    #[test]
+    fn material_tones_run_from_black_to_white() {
+        let tones = material_tones(SolidColor::from_rgb(0x3B, 0x82, 0xF6));
+        assert_eq!(tones[0].to_rgb(), (0, 0, 0));
+        assert_eq!(tones[12].to_rgb(), (255, 255, 255));
+        for pair in tones.windows(2) {
+            assert!(pair[0].perceived_lightness() < pair[1].perceived_lightness());
+        }
+    }
+
+    #[test]
+    fn material_tones_follow_the_tone_values() {
+        let tones = material_tones(SolidColor::from_rgb(0xE5, 0x48, 0x4D));
+        for (tone, color) in MATERIAL_TONES.iter().zip(tones) {
+            let l = color.perceived_lightness();
+            assert!((l - *tone as f64 / 100.0).abs() < 0.01, "tone {tone}: {l}");
+        }
+    }
+
+    fn brand() -> Vec<(String, SolidColor)> {
+        vec![
+            ("Ink".to_string(), SolidColor::from_rgb(0x11, 0x18, 0x27)),
+            ("Sky".to_string(), SolidColor::from_rgb(0x3B, 0x82, 0xF6)),
+            ("Sun".to_string(), SolidColor::from_rgb(0xFA, 0xCC, 0x15)),
+        ]
+    }
+
+    #[test]
+    fn name_in_exact_match() {
+        let palette = brand();
+        let sky = SolidColor::from_hex("3B82F6").unwrap();
+        assert_eq!(name_in(&palette, &sky, 0.0), Some("Sky"));
+        let off = SolidColor::from_hex("3B82F7").unwrap();
+        assert_eq!(name_in(&palette, &off, 0.0), None);
+    }
+
+    #[test]
+    fn name_in_nearest_within_threshold() {
+        let palette = brand();
+        let near_sun = SolidColor::from_hex("F8CA1A").unwrap();
+        assert!(delta_e(&near_sun, &palette[2].1) < 0.02);
+        assert_eq!(name_in(&palette, &near_sun, 0.02), Some("Sun"));
+        let green = SolidColor::from_hex("22C55E").unwrap();
+        assert_eq!(name_in(&palette, &green, 0.02), None);
+        assert_eq!(name_in(&[], &green, 1.0), None);
+    }
+
+    #[test]
+    fn design_tokens_json_shape() {
+        let ramp = [
+            (50, SolidColor::from_rgb(0xEE, 0xF4, 0xFF)),
+            (100, SolidColor::from_rgba(0.0, 0.4, 1.0, 0.5)),
+        ];
+        assert_eq!(
+            to_design_tokens("blue", &ramp),
+            "{\n  \"blue\": {\n    \"50\": \"#EEF4FF\",\n    \"100\": \"#0066FF80\"\n  }\n}"
+        );
+    }
+
+    #[test]
+    fn design_tokens_escape_names() {
+        let ramp = [("a\"b", SolidColor::from_rgb(0, 0, 0))];
+        let json = to_design_tokens("brand\\blue", &ramp);
+        assert!(json.contains("\"brand\\\\blue\""));
+        assert!(json.contains("\"a\\\"b\": \"#000000\""));
+    }
+
+    #[test]
+    fn accessible_set_meets_aa() {
+        let base = SolidColor::from_rgb(0x3B, 0x82, 0xF6);
+        for bg in [
+            SolidColor::from_rgb(255, 255, 255),
+            SolidColor::from_rgb(0x11, 0x18, 0x27),
+        ] {
+            let set = accessible_set(base, bg, 6);
+            assert_eq!(set.len(), 6);
+            for c in &set {
+                let ratio = c.contrast_ratio(&bg);
+                assert!(
+                    ratio >= constants::CONTRAST_AA,
+                    "{} on {}: {ratio}",
+                    c.to_hex(),
+                    bg.to_hex()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn accessible_set_keeps_alpha_and_length() {
+        let base = SolidColor::from_rgba(0.9, 0.28, 0.3, 0.5);
+        let set = accessible_set(base, SolidColor::from_rgb(255, 255, 255), 4);
+        assert_eq!(set.len(), 4);
+        assert!(set.iter().all(|c| c.a() == 0.5));
+        assert!(accessible_set(base, base, 0).is_empty());
+    }
+
+    #[test]
+    fn nearest_returns_index_and_distance() {
+        let colors: Vec<SolidColor> = brand().into_iter().map(|(_, c)| c).collect();
+        let (i, d) = nearest(&colors, &colors[1]).unwrap();
+        assert_eq!((i, d), (1, 0.0));
+
+        let near_sun = SolidColor::from_hex("F8CA1A").unwrap();
+        let (i, d) = nearest(&colors, &near_sun).unwrap();
+        assert_eq!(i, 2);
+        assert_eq!(d, delta_e(&colors[2], &near_sun));
+    }
+
+    #[test]
+    fn nearest_ties_go_first_and_empty_is_none() {
+        let red = SolidColor::from_rgb(255, 0, 0);
+        let (i, _) = nearest(&[red, red], &red).unwrap();
+        assert_eq!(i, 0);
+        assert_eq!(nearest(&[], &red), None);
+    }
+}
@@ -0,0 +1,347 @@
+//! Saturation ramp (0.0–1.0).
+//!
+//! Horizontal gradient from gray (left) to the fully saturated color
+//! (right) at the current hue and brightness, as a rasterized image.
+
+use std::sync::Arc;
+
+use floem::kurbo::Rect;
+use floem::peniko::{self, Blob, Color};
+
+use floem::reactive::{RwSignal, SignalGet, SignalUpdate, create_effect};
+use floem::views::Decorators;
+use floem::{
+    View, ViewId,
+    context::{ComputeLayoutCx, EventCx, PaintCx, UpdateCx},
+    event::{Event, EventPropagation},
+};
+
+use floem_renderer::Renderer;
+
+use crate::config::StepSize;
+use crate::constants;
+use crate::math;
+use crate::theme::Theme;
+
+/// Rasterize horizontal gradient: gray `(v, v, v)` on the left -> `(r, g, b)` on the right.
+///
+/// At fixed hue and brightness, RGB is linear in saturation, so a straight
+/// RGB lerp between the endpoints is exact.
+fn rasterize_saturation_gradient(
+    width: u32,
+    height: u32,
+    v: f64,
+    r: f64,
+    g: f64,
+    b: f64,
+) -> Vec<u8> {
+    let mut buf = vec![0u8; (width * height * 4) as usize];
+    for px in 0..width {
+        let t = px as f64 / (width - 1).max(1) as f64; // 0 at left, 1 at right
+        let cr = ((v + t * (r - v)) * 255.0 + 0.5) as u8;
+        let cg = ((v + t * (g - v)) * 255.0 + 0.5) as u8;
+        let cb = ((v + t * (b - v)) * 255.0 + 0.5) as u8;
+        for py in 0..height {
+            let offset = ((py * width + px) * 4) as usize;
+            buf[offset] = cr;
+            buf[offset + 1] = cg;
+            buf[offset + 2] = cb;
+            buf[offset + 3] = 255;
+        }
+    }
+    buf
+}
+
+enum SaturationUpdate {
+    Value(f64),
+    /// Brightness, then the fully saturated color at the current hue and brightness.
+    Ramp(f64, f64, f64, f64),
+}
+
+pub(crate) struct SaturationSlider {
+    id: ViewId,
+    held: bool,
+    /// Whether the control has keyboard focus (draws the focus ring).
+    focused: bool,
+    saturation: f64,
+    brightness: f64,
+    full_r: f64,
+    full_g: f64,
+    full_b: f64,
+    size: floem::taffy::prelude::Size<f32>,
+    theme: Theme,
+    on_change: Option<Box<dyn Fn(f64)>>,
+    /// Scroll step size.
+    step: StepSize,
+    /// Cached gradient image, rasterized at a fixed resolution.
+    grad_img: Option<peniko::Image>,
+    grad_hash: Vec<u8>,
+    cached_color: (u8, u8, u8),
+}
+
+/// Creates a horizontal saturation ramp.
+///
+/// - `hue`, `brightness`: read-only, used to compute the ramp's colors.
+/// - `saturation`: 0.0 (gray, left) to 1.0 (full color, right).
+/// - `theme`: thumb and track outline colors.
+/// - `step`: scroll step size.
+/// - `quantize`: if set, emitted values snap to `1 / quantize` increments.
+pub(crate) fn saturation_slider(
+    hue: RwSignal<f64>,
+    saturation: RwSignal<f64>,
+    brightness: RwSignal<f64>,
+    theme: Theme,
+    step: StepSize,
+    quantize: Option<f64>,
+) -> SaturationSlider {
+    let id = ViewId::new();
+
+    create_effect(move |_| {
+        let s = saturation.get();
+        id.update_state(SaturationUpdate::Value(s));
+    });
+
+    create_effect(move |_| {
+        let h = hue.get();
+        let b = brightness.get();
+        let (r, g, bl) = math::hsb_to_rgb(h, 1.0, b);
+        id.update_state(SaturationUpdate::Ramp(b, r, g, bl));
+    });
+
+    let b = brightness.get_untracked();
+    let (r, g, bl) = math::hsb_to_rgb(hue.get_untracked(), 1.0, b);
+
+    SaturationSlider {
+        id,
+        held: false,
+        focused: false,
+        saturation: saturation.get_untracked(),
+        brightness: b,
+        full_r: r,
+        full_g: g,
+        full_b: bl,
+        size: Default::default(),
+        theme,
+        on_change: Some(Box::new(move |val| {
+            saturation.set(match quantize {
+                Some(n) => math::quantize(val, n),
+                None => val,
+            });
+        })),
+        step,
+        grad_img: None,
+        grad_hash: Vec::new(),
+        cached_color: (0, 0, 0),
+    }
+    .style(|s| {
+        s.height(constants::SLIDER_HEIGHT)
+            .border_radius(constants::THUMB_RADIUS as f32)
+            .cursor(floem::style::CursorStyle::Pointer)
+    })
+    .keyboard_navigable()
+}
+
+impl SaturationSlider {
+    fn update_from_pointer(&mut self, x: f64) {
+        let w = self.size.width as f64;
+        let r = constants::THUMB_RADIUS;
+        let usable = w - 2.0 * r;
+        if usable > 0.0 {
+            // Left = gray, right = full saturation
+            self.saturation = ((x - r) / usable).clamp(0.0, 1.0);
+        }
+    }
+
+    /// Rasterize at a fixed resolution, only when the saturated end color
+    /// changes (it encodes both hue and brightness).
+    fn ensure_gradient_image(&mut self) {
+        let color_key = (
+            (self.full_r * 255.0 + 0.5) as u8,
+            (self.full_g * 255.0 + 0.5) as u8,
+            (self.full_b * 255.0 + 0.5) as u8,
+        );
+        if self.grad_img.is_some() && self.cached_color == color_key {
+            return;
+        }
+
+        let pw = constants::SLIDER_RASTER_WIDTH;
+        let ph = constants::SLIDER_RASTER_HEIGHT;
+        let pixels = rasterize_saturation_gradient(
+            pw,
+            ph,
+            self.brightness,
+            self.full_r,
+            self.full_g,
+            self.full_b,
+        );
+        let blob = Blob::new(Arc::new(pixels));
+        let img = peniko::Image::new(blob, peniko::Format::Rgba8, pw, ph);
+
+        self.grad_hash = [
+            b"sat" as &[u8],
+            &color_key.0.to_le_bytes(),
+            &color_key.1.to_le_bytes(),
+            &color_key.2.to_le_bytes(),
+        ]
+        .concat();
+        self.grad_img = Some(img);
+        self.cached_color = color_key;
+    }
+}
+
+impl View for SaturationSlider {
+    fn id(&self) -> ViewId {
+        self.id
+    }
+
+    fn update(&mut self, _cx: &mut UpdateCx, state: Box<dyn std::any::Any>) {
+        if let Ok(update) = state.downcast::<SaturationUpdate>() {
+            match *update {
+                SaturationUpdate::Value(val) => self.saturation = val,
+                SaturationUpdate::Ramp(v, r, g, b) => {
+                    self.brightness = v;
+                    self.full_r = r;
+                    self.full_g = g;
+                    self.full_b = b;
+                }
+            }
+            self.id.request_layout();
+        }
+    }
+
+    fn event_before_children(&mut self, cx: &mut EventCx, event: &Event) -> EventPropagation {
+        match event {
+            Event::PointerDown(e) => {
+                cx.update_active(self.id());
+                self.held = true;
+                self.update_from_pointer(e.pos.x);
+                if let Some(cb) = &self.on_change {
+                    cb(self.saturation);
+                }
+                self.id.request_layout();
+                EventPropagation::Stop
+            }
+            Event::PointerMove(e) => {
+                if self.held {
+                    self.update_from_pointer(e.pos.x);
+                    if let Some(cb) = &self.on_change {
+                        cb(self.saturation);
+                    }
+                    self.id.request_layout();
+                    EventPropagation::Stop
+                } else {
+                    EventPropagation::Continue
+                }
+            }
+            Event::PointerUp(_) => {
+                self.held = false;
+                EventPropagation::Continue
+            }
+            Event::FocusGained => {
+                self.focused = true;
+                self.id.request_paint();
+                EventPropagation::Continue
+            }
+            Event::FocusLost => {
+                self.held = false;
+                self.focused = false;
+                self.id.request_paint();
+                EventPropagation::Continue
+            }
+            Event::PointerWheel(e) => {
+                let large = e.modifiers.contains(floem::keyboard::Modifiers::SHIFT);
+                let delta = self.step.for_scroll(e.delta.y, large);
+                self.saturation = (self.saturation + delta).clamp(0.0, 1.0);
+                if let Some(cb) = &self.on_change {
+                    cb(self.saturation);
+                }
+                self.id.request_layout();
+                EventPropagation::Stop
+            }
+            _ => EventPropagation::Continue,
+        }
+    }
+
+    fn compute_layout(&mut self, _cx: &mut ComputeLayoutCx) -> Option<Rect> {
+        let layout = self.id.get_layout().unwrap_or_default();
+        self.size = layout.size;
+        None
+    }
+
+    fn paint(&mut self, cx: &mut PaintCx) {
+        let w = self.size.width as f64;
+        let h = self.size.height as f64;
+        if w == 0.0 || h == 0.0 {
+            return;
+        }
+        let rect = Rect::new(0.0, 0.0, w, h);
+        let rrect = rect.to_rounded_rect(constants::THUMB_RADIUS);
+
+        // Rounded ends on sliders
+        cx.save();
+        cx.clip(&rrect);
+
+        // Gray (left) -> full saturation (right) as raster
+        self.ensure_gradient_image();
+        if let Some(ref img) = self.grad_img {
+            cx.draw_img(
+                floem_renderer::Img {
+                    img: img.clone(),
+                    hash: &self.grad_hash,
+                },
+                rect,
+            );
+        }
+
+        cx.restore();
+
+        // Slider outline
+        cx.stroke(
+            &rrect,
+            self.theme.track_outline,
+            &floem::kurbo::Stroke::new(1.0),
+        );
+
+        // Filled thumbs (same pattern as color wheel cursor)
+        let radius = constants::THUMB_RADIUS;
+        let thumb_x = (radius + self.saturation * (w - 2.0 * radius)).round();
+        let thumb_cy = (h / 2.0).round();
+        cx.fill(
+            &floem::kurbo::Circle::new((thumb_x, thumb_cy), radius + 1.0),
+            self.theme.thumb_shadow,
+            0.0,
+        );
+        cx.fill(
+            &floem::kurbo::Circle::new((thumb_x, thumb_cy), radius),
+            self.theme.thumb_ring,
+            0.0,
+        );
+        cx.fill(
+            &floem::kurbo::Circle::new((thumb_x, thumb_cy), radius - 2.0),
+            self.theme.thumb_inner_ring,
+            0.0,
+        );
+        let v = self.brightness;
+        let s = self.saturation;
+        cx.fill(
+            &floem::kurbo::Circle::new((thumb_x, thumb_cy), radius - 3.0),
+            Color::rgb(
+                v + s * (self.full_r - v),
+                v + s * (self.full_g - v),
+                v + s * (self.full_b - v),
+            ),
+            0.0,
+        );
+
+        // Focus ring, inset so it stays within the control's bounds
+        if self.focused {
+            cx.stroke(
+                &rect
+                    .inset(-1.0)
+                    .to_rounded_rect(constants::THUMB_RADIUS - 1.0),
+                self.theme.focus_ring,
+                &floem::kurbo::Stroke::new(2.0),
+            );
+        }
+    }
+}
@@ -0,0 +1,184 @@
+//! Current-color swatch, optionally scrubbable or split to compare with the
+//! color the picker opened with.
+//!
+//! With scrubbing enabled, dragging horizontally adjusts brightness and
+//! dragging vertically adjusts saturation (up is more saturated). Movement
+//! under [`SCRUB_THRESHOLD`] is ignored so plain clicks don't nudge the color.
+//!
+//! In compare mode the left half shows the original color and the right half
+//! the current one; clicking the left half reverts to the original.
+
+use floem::kurbo::{Point, Rect, Stroke};
+use floem::peniko::Color;
+use floem::reactive::{RwSignal, SignalGet, SignalUpdate, create_effect};
+use floem::views::Decorators;
+use floem::{
+    View, ViewId,
+    context::{ComputeLayoutCx, EventCx, PaintCx, UpdateCx},
+    event::{Event, EventPropagation},
+};
+
+use floem_renderer::Renderer;
+
+#[cfg(feature = "alpha")]
+use crate::checkerboard;
+use crate::color::SolidColor;
+use crate::constants;
+
+/// Pointer travel in pixels before a press turns into a scrub.
+const SCRUB_THRESHOLD: f64 = 3.0;
+/// Pointer travel in pixels for a full 0.0–1.0 sweep.
+const SCRUB_RANGE: f64 = 200.0;
+
+const BORDER: Color = Color::rgb8(180, 180, 180);
+
+pub(crate) struct Swatch {
+    id: ViewId,
+    color: SolidColor,
+    color_signal: RwSignal<SolidColor>,
+    /// Color the picker opened with, shown on the left half when comparing.
+    original: Option<SolidColor>,
+    size: floem::taffy::prelude::Size<f32>,
+    /// Saturation and brightness signals edited by scrubbing, if enabled.
+    scrub: Option<(RwSignal<f64>, RwSignal<f64>)>,
+    /// Press position and the saturation/brightness at that moment.
+    press: Option<(Point, f64, f64)>,
+    scrubbing: bool,
+}
+
+/// Creates the swatch showing `color`.
+///
+/// - `saturation`/`brightness`: HSB signals edited by scrubbing
+/// - `scrub`: enable drag-to-scrub
+/// - `compare`: split to show the initial color beside the current one
+pub(crate) fn swatch(
+    color: RwSignal<SolidColor>,
+    saturation: RwSignal<f64>,
+    brightness: RwSignal<f64>,
+    scrub: bool,
+    compare: bool,
+) -> Swatch {
+    let id = ViewId::new();
+
+    create_effect(move |_| {
+        id.update_state(color.get());
+    });
+
+    Swatch {
+        id,
+        color: color.get_untracked(),
+        color_signal: color,
+        original: compare.then(|| color.get_untracked()),
+        size: Default::default(),
+        scrub: scrub.then_some((saturation, brightness)),
+        press: None,
+        scrubbing: false,
+    }
+    .style(move |s| {
+        s.width(if compare { 48.0 } else { 32.0 })
+            .height(32.0)
+            .apply_if(scrub || compare, |s| {
+                s.cursor(floem::style::CursorStyle::Pointer)
+            })
+    })
+}
+
+impl View for Swatch {
+    fn id(&self) -> ViewId {
+        self.id
+    }
+
+    fn update(&mut self, _cx: &mut UpdateCx, state: Box<dyn std::any::Any>) {
+        if let Ok(color) = state.downcast::<SolidColor>() {
+            self.color = *color;
+            self.id.request_paint();
+        }
+    }
+
+    fn event_before_children(&mut self, cx: &mut EventCx, event: &Event) -> EventPropagation {
+        // Click on the original half reverts
+        if let Event::PointerUp(e) = event
+            && !self.scrubbing
+            && let Some(original) = self.original
+            && e.pos.x < self.size.width as f64 / 2.0
+        {
+            self.press = None;
+            self.color_signal.set(original);
+            return EventPropagation::Stop;
+        }
+        let Some((saturation, brightness)) = self.scrub else {
+            return EventPropagation::Continue;
+        };
+        match event {
+            Event::PointerDown(e) => {
+                cx.update_active(self.id());
+                self.press = Some((
+                    e.pos,
+                    saturation.get_untracked(),
+                    brightness.get_untracked(),
+                ));
+                self.scrubbing = false;
+                EventPropagation::Continue
+            }
+            Event::PointerMove(e) => {
+                let Some((start, s0, b0)) = self.press else {
+                    return EventPropagation::Continue;
+                };
+                let (dx, dy) = (e.pos.x - start.x, e.pos.y - start.y);
+                if !self.scrubbing && dx.hypot(dy) < SCRUB_THRESHOLD {
+                    return EventPropagation::Continue;
+                }
+                self.scrubbing = true;
+                brightness.set((b0 + dx / SCRUB_RANGE).clamp(0.0, 1.0));
+                saturation.set((s0 - dy / SCRUB_RANGE).clamp(0.0, 1.0));
+                EventPropagation::Stop
+            }
+            Event::PointerUp(_) | Event::FocusLost => {
+                self.press = None;
+                // Swallow the release that ends a scrub so it isn't a click
+                if std::mem::take(&mut self.scrubbing) {
+                    EventPropagation::Stop
+                } else {
+                    EventPropagation::Continue
+                }
+            }
+            _ => EventPropagation::Continue,
+        }
+    }
+
+    fn compute_layout(&mut self, _cx: &mut ComputeLayoutCx) -> Option<Rect> {
+        let layout = self.id.get_layout().unwrap_or_default();
+        self.size = layout.size;
+        None
+    }
+
+    fn paint(&mut self, cx: &mut PaintCx) {
+        let w = self.size.width as f64;
+        let h = self.size.height as f64;
+        if w == 0.0 || h == 0.0 {
+            return;
+        }
+        let radius = constants::RADIUS as f64;
+        let rect = Rect::new(0.0, 0.0, w, h);
+        cx.save();
+        cx.clip(&rect.to_rounded_rect(radius));
+        #[cfg(feature = "alpha")]
+        if self.color.a() < 1.0 || self.original.is_some_and(|c| c.a() < 1.0) {
+            checkerboard::paint_checkerboard(cx, rect);
+        }
+        let fill = Color::from;
+        match self.original {
+            Some(original) => {
+                cx.fill(&Rect::new(0.0, 0.0, w / 2.0, h), fill(original), 0.0);
+                cx.fill(&Rect::new(w / 2.0, 0.0, w, h), fill(self.color), 0.0);
+            }
+            None => cx.fill(&rect, fill(self.color), 0.0),
+        }
+        cx.restore();
+        cx.stroke(
+            &Rect::new(0.5, 0.5, w - 0.5, h - 0.5).to_rounded_rect(radius),
+            BORDER,
+            &Stroke::new(1.0),
+        );
+    }
+}
@@ -0,0 +1,71 @@
+//! Swatch image export (requires the `image` feature).
+
+use std::borrow::Cow;
+use std::io::Cursor;
+use std::path::PathBuf;
+
+use crate::color::SolidColor;
+
+/// Side length in pixels of exported swatch images.
+pub(crate) const SWATCH_SIZE: u32 = 64;
+
+impl SolidColor {
+    /// Render a solid `width`×`height` image filled with this color.
+    pub fn to_rgba_image(&self, width: u32, height: u32) -> image::RgbaImage {
+        let (r, g, b) = self.to_rgb();
+        let a = (self.a() * 255.0).round() as u8;
+        image::RgbaImage::from_pixel(width, height, image::Rgba([r, g, b, a]))
+    }
+}
+
+/// Encode a square swatch of `color` as PNG bytes.
+pub fn swatch_png(color: &SolidColor, size: u32) -> image::ImageResult<Vec<u8>> {
+    let mut out = Cursor::new(Vec::new());
+    color
+        .to_rgba_image(size, size)
+        .write_to(&mut out, image::ImageFormat::Png)?;
+    Ok(out.into_inner())
+}
+
+/// Place a swatch image of `color` on the clipboard.
+///
+/// Where the platform clipboard can't hold images, writes a PNG to the temp
+/// directory instead and returns its path. Returns `Ok(None)` when the image
+/// went to the clipboard.
+pub fn copy_swatch_image(color: &SolidColor) -> std::io::Result<Option<PathBuf>> {
+    let img = color.to_rgba_image(SWATCH_SIZE, SWATCH_SIZE);
+    let copied = arboard::Clipboard::new()
+        .and_then(|mut clipboard| {
+            clipboard.set_image(arboard::ImageData {
+                width: img.width() as usize,
+                height: img.height() as usize,
+                bytes: Cow::Borrowed(img.as_raw()),
+            })
+        })
+        .is_ok();
+    if copied {
+        return Ok(None);
+    }
+
+    let png = swatch_png(color, SWATCH_SIZE).map_err(std::io::Error::other)?;
+    let path = std::env::temp_dir().join(format!("swatch-{}.png", color.to_hex()));
+    std::fs::write(&path, png)?;
+    Ok(Some(path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn swatch_png_decodes_to_the_color() {
+        let color = SolidColor::from_hex("3B82F680").unwrap();
+        let png = swatch_png(&color, 4).unwrap();
+        assert!(png.starts_with(b"\x89PNG\r\n\x1a\n"));
+        let decoded = image::load_from_memory_with_format(&png, image::ImageFormat::Png)
+            .unwrap()
+            .to_rgba8();
+        assert_eq!(decoded.dimensions(), (4, 4));
+        assert!(decoded.pixels().all(|p| p.0 == [0x3B, 0x82, 0xF6, 0x80]));
+    }
+}
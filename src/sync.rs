@@ -0,0 +1,138 @@
+//! Two-way sync between ground-truth signals and a derived color model.
+//!
+//! Every derived row (HSL, RGB, ...) follows the same pattern: a forward
+//! effect recomputes the derived signals when the source changes, and a back
+//! effect pushes user edits of the derived signals into the source. A
+//! non-reactive guard stops the forward update from echoing back through the
+//! back effect, so the source is never rewritten from its own echo.
+
+use std::cell::Cell;
+use std::rc::Rc;
+
+use floem::reactive::{RwSignal, SignalGet, SignalUpdate, create_effect};
+
+/// Wire `derived` to the three `source` signals (usually HSB) in both directions.
+///
+/// - `to_derived`: source -> derived values.
+/// - `to_source`: derived values plus the current source -> new source. The
+///   current source is passed so conversions can keep components the derived
+///   model can't express (e.g. hue of a gray).
+/// - `tolerance`: changes at or below this are ignored in both directions,
+///   which keeps rounding noise from bouncing between the two models.
+pub(crate) fn sync_derived<const N: usize>(
+    source: [RwSignal<f64>; 3],
+    derived: [RwSignal<f64>; N],
+    tolerance: f64,
+    to_derived: impl Fn([f64; 3]) -> [f64; N] + 'static,
+    to_source: impl Fn([f64; N], [f64; 3]) -> [f64; 3] + 'static,
+) {
+    let from_source = Rc::new(Cell::new(false));
+
+    // Source -> derived
+    let guard_fwd = from_source.clone();
+    create_effect(move |_| {
+        let src = source.map(|sig| sig.get());
+        let new = to_derived(src);
+        let changed = derived
+            .iter()
+            .zip(new)
+            .any(|(sig, v)| (sig.get_untracked() - v).abs() > tolerance);
+        if changed {
+            guard_fwd.set(true);
+            for (sig, v) in derived.iter().zip(new) {
+                sig.set(v);
+            }
+            guard_fwd.set(false);
+        }
+    });
+
+    // Derived -> source (user edits)
+    let guard_back = from_source;
+    create_effect(move |_| {
+        let vals = derived.map(|sig| sig.get());
+        if guard_back.get() {
+            return;
+        }
+        let current = source.map(|sig| sig.get_untracked());
+        let new = to_source(vals, current);
+        for ((sig, cur), v) in source.iter().zip(current).zip(new) {
+            if (cur - v).abs() > tolerance {
+                sig.set(v);
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::math;
+
+    fn rgb_of([h, s, b]: [f64; 3]) -> [f64; 3] {
+        let (r, g, b) = math::hsb_to_rgb(h, s, b);
+        [r, g, b]
+    }
+
+    /// RGB -> HSB, keeping the current hue for grays.
+    fn hsb_of([r, g, b]: [f64; 3], [h0, _, _]: [f64; 3]) -> [f64; 3] {
+        let (h, s, v) = math::rgb_to_hsb(r, g, b);
+        [if s == 0.0 { h0 } else { h }, s, v]
+    }
+
+    fn assert_near(sigs: [RwSignal<f64>; 3], want: [f64; 3]) {
+        for (sig, w) in sigs.iter().zip(want) {
+            let got = sig.get_untracked();
+            assert!((got - w).abs() < 1e-9, "{got} != {w}");
+        }
+    }
+
+    type Channels = [RwSignal<f64>; 3];
+
+    /// Sync HSB to RGB, counting how often the back conversion runs.
+    fn wire(hsb: [f64; 3]) -> (Channels, Channels, Rc<Cell<u32>>) {
+        let source = hsb.map(RwSignal::new);
+        let derived = [RwSignal::new(0.0), RwSignal::new(0.0), RwSignal::new(0.0)];
+        let back_runs = Rc::new(Cell::new(0));
+        let runs = back_runs.clone();
+        sync_derived(source, derived, 0.001, rgb_of, move |vals, current| {
+            runs.set(runs.get() + 1);
+            hsb_of(vals, current)
+        });
+        (source, derived, back_runs)
+    }
+
+    #[test]
+    fn source_edits_reach_derived_without_echo() {
+        let (source, derived, back_runs) = wire([0.0, 1.0, 1.0]);
+        assert_near(derived, [1.0, 0.0, 0.0]);
+        let initial_runs = back_runs.get();
+
+        source[0].set(0.5);
+        assert_near(derived, [0.0, 1.0, 1.0]);
+        assert_near(source, [0.5, 1.0, 1.0]);
+        // The forward update is guarded, so it never converts back
+        assert_eq!(back_runs.get(), initial_runs);
+    }
+
+    #[test]
+    fn derived_edits_converge() {
+        let (source, derived, back_runs) = wire([0.0, 1.0, 1.0]);
+        let initial_runs = back_runs.get();
+
+        derived[1].set(1.0);
+        assert_near(source, [1.0 / 6.0, 1.0, 1.0]);
+        assert_near(derived, [1.0, 1.0, 0.0]);
+        // One conversion per edit: the resulting source change doesn't
+        // bounce back into another round
+        assert_eq!(back_runs.get(), initial_runs + 1);
+    }
+
+    #[test]
+    fn derived_gray_keeps_source_hue() {
+        let (source, derived, _) = wire([0.5, 1.0, 1.0]);
+        derived[0].set(1.0);
+        assert_near(source, [0.5, 0.0, 1.0]);
+        assert_near(derived, [1.0, 1.0, 1.0]);
+    }
+}
@@ -0,0 +1,57 @@
+//! macOS system color swatches.
+//!
+//! Reads the built-in "Apple" `NSColorList` (the swatches shown in the
+//! system color panel) via Objective-C FFI. Colors are converted through
+//! sRGB like the eyedropper's.
+
+use objc2::msg_send;
+use objc2::runtime::{AnyClass, AnyObject};
+
+use crate::color::SolidColor;
+use crate::ns_color::ns_color_to_solid;
+
+/// Name of the system color list to read.
+const COLOR_LIST_NAME: &std::ffi::CStr = c"Apple";
+
+/// Fetch the system color swatches, in the list's order.
+///
+/// Returns an empty list if the color list is unavailable.
+/// Must be called from the main thread (view construction satisfies this).
+pub(crate) fn system_colors() -> Vec<SolidColor> {
+    let (Some(list_cls), Some(str_cls)) = (AnyClass::get("NSColorList"), AnyClass::get("NSString"))
+    else {
+        return Vec::new();
+    };
+
+    let mut colors = Vec::new();
+    unsafe {
+        let name: *const AnyObject =
+            msg_send![str_cls, stringWithUTF8String: COLOR_LIST_NAME.as_ptr()];
+        if name.is_null() {
+            return colors;
+        }
+        let list: *const AnyObject = msg_send![list_cls, colorListNamed: &*name];
+        if list.is_null() {
+            return colors;
+        }
+        let keys: *const AnyObject = msg_send![&*list, allKeys];
+        if keys.is_null() {
+            return colors;
+        }
+        let count: usize = msg_send![&*keys, count];
+        for i in 0..count {
+            let key: *const AnyObject = msg_send![&*keys, objectAtIndex: i];
+            if key.is_null() {
+                continue;
+            }
+            let ns_color: *const AnyObject = msg_send![&*list, colorWithKey: &*key];
+            if ns_color.is_null() {
+                continue;
+            }
+            if let Some(c) = ns_color_to_solid(&*ns_color) {
+                colors.push(c);
+            }
+        }
+    }
+    colors
+}
@@ -0,0 +1,34 @@
+//! Colors for the picker's custom-painted controls.
+
+use floem::peniko::Color;
+
+/// Colors used to paint the wheel cursor, slider thumbs and tracks, and
+/// focus rings.
+///
+/// The defaults suit light backgrounds; dark themes will usually want a
+/// lighter shadow and inner ring so the outlines stay visible.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Theme {
+    /// Soft shadow ring just outside thumbs and the wheel cursor.
+    pub thumb_shadow: Color,
+    /// Main ring of thumbs and the wheel cursor.
+    pub thumb_ring: Color,
+    /// Thin ring separating the main ring from the color fill.
+    pub thumb_inner_ring: Color,
+    /// Outline around slider tracks.
+    pub track_outline: Color,
+    /// 2px ring drawn on the wheel or slider that has keyboard focus.
+    pub focus_ring: Color,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self {
+            thumb_shadow: Color::rgba8(0, 0, 0, 80),
+            thumb_ring: Color::WHITE,
+            thumb_inner_ring: Color::rgba8(0, 0, 0, 150),
+            track_outline: Color::rgba8(0, 0, 0, 40),
+            focus_ring: Color::rgb8(0, 122, 255),
+        }
+    }
+}
@@ -0,0 +1,31 @@
+//! Tick marks drawn over the 1D slider tracks.
+
+use floem::context::PaintCx;
+use floem::kurbo::{Line, Stroke};
+use floem::peniko::Color;
+
+use floem_renderer::Renderer;
+
+use crate::constants;
+
+/// Paint a thin vertical tick for each value in `ticks` (0.0–1.0).
+///
+/// Values map to x the same way the slider thumbs do (1.0 at the left), so a
+/// tick lines up with the thumb when the slider holds that value. Sizes are in
+/// logical pixels and are scaled with the rest of the view.
+pub(crate) fn paint_ticks(cx: &mut PaintCx, width: f64, height: f64, ticks: &[f64]) {
+    let radius = constants::THUMB_RADIUS;
+    let usable = width - 2.0 * radius;
+    if usable <= 0.0 {
+        return;
+    }
+    let inset = height * 0.25;
+    for &t in ticks {
+        let x = (radius + (1.0 - t.clamp(0.0, 1.0)) * usable).round() + 0.5;
+        cx.stroke(
+            &Line::new((x, inset), (x, height - inset)),
+            Color::rgba8(255, 255, 255, 160),
+            &Stroke::new(1.0),
+        );
+    }
+}